@@ -0,0 +1,79 @@
+//! Helpers to animate the values fed to widgets between frames.
+//!
+//! # Examples
+//!
+//! ```
+//! # extern crate tui;
+//! # use tui::animation::{Easing, Tween};
+//! # use tui::widgets::Gauge;
+//! # fn main() {
+//! let tween = Tween::new(0.2, 0.8).easing(Easing::EaseInOut);
+//! // Half way through the transition
+//! Gauge::default().ratio(tween.at(0.5));
+//! # }
+//! ```
+
+/// Describes how the progress of a transition is mapped to the progress of the animated value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// The value changes at a constant speed
+    Linear,
+    /// The value starts and ends slowly, changing faster in the middle of the transition
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the progress of the value given the progress `t` of the transition. Both are
+    /// between 0.0 and 1.0, `t` being clamped to this range.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// A transition between two values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    pub from: f64,
+    pub to: f64,
+    pub easing: Easing,
+}
+
+impl Tween {
+    pub fn new(from: f64, to: f64) -> Tween {
+        Tween {
+            from,
+            to,
+            easing: Easing::Linear,
+        }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Tween {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the value at the given point `t` of the transition, 0.0 being its start and 1.0
+    /// its end
+    pub fn at(&self, t: f64) -> f64 {
+        self.from + (self.to - self.from) * self.easing.apply(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ease_in_out_curve() {
+        let tween = Tween::new(10.0, 20.0).easing(Easing::EaseInOut);
+        assert_eq!(tween.at(0.0), 10.0);
+        assert_eq!(tween.at(0.5), 15.0);
+        assert_eq!(tween.at(1.0), 20.0);
+        assert!(tween.at(0.25) < Tween::new(10.0, 20.0).at(0.25));
+        assert_eq!(tween.at(2.0), 20.0);
+    }
+}
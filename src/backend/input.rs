@@ -0,0 +1,186 @@
+use std::str;
+
+/// A key pressed by the user, as decoded from the bytes read on the terminal input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Backspace,
+    Esc,
+    /// Function key, from F1 to F12
+    F(u8),
+    Ctrl(char),
+    Alt(char),
+}
+
+/// Iterator over the keys encoded in a sequence of bytes read on the terminal input
+pub struct Keys<'a> {
+    bytes: &'a [u8],
+}
+
+/// Decodes the keys encoded in the given bytes. Unknown escape sequences are skipped and
+/// decoding stops at the first incomplete sequence.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::backend::{keys, Key};
+/// let decoded = keys(b"a\x1b[A\x01\x1bx").collect::<Vec<Key>>();
+/// assert_eq!(decoded, vec![Key::Char('a'), Key::Up, Key::Ctrl('a'), Key::Alt('x')]);
+/// ```
+pub fn keys<'a>(bytes: &'a [u8]) -> Keys<'a> {
+    Keys { bytes }
+}
+
+impl<'a> Iterator for Keys<'a> {
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Key> {
+        loop {
+            let (key, len) = decode(self.bytes);
+            if len == 0 {
+                return None;
+            }
+            self.bytes = &self.bytes[len..];
+            if key.is_some() {
+                return key;
+            }
+        }
+    }
+}
+
+/// Decodes the first key of the given bytes and returns it along with the number of bytes used
+/// to encode it. No key is returned for unknown sequences and nothing is consumed when the
+/// sequence is incomplete.
+fn decode(bytes: &[u8]) -> (Option<Key>, usize) {
+    match bytes.first() {
+        None => (None, 0),
+        Some(&b'\x1b') => decode_escape(bytes),
+        Some(&b'\r') | Some(&b'\n') => (Some(Key::Enter), 1),
+        Some(&b'\x7f') | Some(&b'\x08') => (Some(Key::Backspace), 1),
+        Some(&b'\t') => (Some(Key::Char('\t')), 1),
+        Some(&b @ 0x01..=0x1a) => (Some(Key::Ctrl((b'a' + b - 1) as char)), 1),
+        Some(_) => match decode_char(bytes) {
+            Some((c, len)) => (Some(Key::Char(c)), len),
+            None => (None, 0),
+        },
+    }
+}
+
+/// Decodes a sequence starting with an escape character
+fn decode_escape(bytes: &[u8]) -> (Option<Key>, usize) {
+    match bytes.get(1) {
+        None | Some(&b'\x1b') => (Some(Key::Esc), 1),
+        Some(&b'[') => decode_csi(bytes),
+        Some(&b'O') => match bytes.get(2) {
+            None => (None, 0),
+            Some(&b) => (arrow(b).or_else(|| low_function_key(b)), 3),
+        },
+        Some(_) => match decode_char(&bytes[1..]) {
+            Some((c, len)) => (Some(Key::Alt(c)), len + 1),
+            None => (None, 0),
+        },
+    }
+}
+
+/// Decodes a control sequence, i.e. an escape character followed by a `[`, parameters and a
+/// final byte
+fn decode_csi(bytes: &[u8]) -> (Option<Key>, usize) {
+    let end = match bytes[2..].iter().position(|b| (0x40..=0x7e).contains(b)) {
+        Some(i) => i + 2,
+        None => return (None, 0),
+    };
+    let params = &bytes[2..end];
+    let key = match bytes[end] {
+        b'~' => str::from_utf8(params)
+            .ok()
+            .and_then(|p| p.parse::<u8>().ok())
+            .and_then(function_key),
+        b if params.is_empty() => arrow(b).or_else(|| low_function_key(b)),
+        _ => None,
+    };
+    (key, end + 1)
+}
+
+fn arrow(b: u8) -> Option<Key> {
+    match b {
+        b'A' => Some(Key::Up),
+        b'B' => Some(Key::Down),
+        b'C' => Some(Key::Right),
+        b'D' => Some(Key::Left),
+        _ => None,
+    }
+}
+
+/// F1 to F4 as sent by terminals using the VT100 encoding
+fn low_function_key(b: u8) -> Option<Key> {
+    match b {
+        b'P'..=b'S' => Some(Key::F(b - b'P' + 1)),
+        _ => None,
+    }
+}
+
+/// Function keys sent as `ESC [ <code> ~`
+fn function_key(code: u8) -> Option<Key> {
+    match code {
+        11..=15 => Some(Key::F(code - 10)),
+        17..=21 => Some(Key::F(code - 11)),
+        23 | 24 => Some(Key::F(code - 12)),
+        _ => None,
+    }
+}
+
+/// Decodes the UTF-8 character at the start of the given bytes, invalid bytes being replaced
+/// by `U+FFFD`. Returns `None` if the character is incomplete.
+fn decode_char(bytes: &[u8]) -> Option<(char, usize)> {
+    let len = match bytes[0] {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return Some(('\u{fffd}', 1)),
+    };
+    bytes.get(..len).map(|b| match str::from_utf8(b) {
+        Ok(s) => (s.chars().next().unwrap(), len),
+        Err(_) => ('\u{fffd}', 1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_sequences_are_decoded() {
+        let cases: &[(&[u8], Key)] = &[
+            (b"\x1b[A", Key::Up),
+            (b"\x1b[B", Key::Down),
+            (b"\x1b[C", Key::Right),
+            (b"\x1bOD", Key::Left),
+            (b"\x1bOP", Key::F(1)),
+            (b"\x1b[15~", Key::F(5)),
+            (b"\x1b[24~", Key::F(12)),
+            (b"\x1b", Key::Esc),
+            (b"\x1bq", Key::Alt('q')),
+            (b"\x17", Key::Ctrl('w')),
+            (b"\r", Key::Enter),
+            (b"\x7f", Key::Backspace),
+            ("é".as_bytes(), Key::Char('é')),
+        ];
+        for &(bytes, key) in cases {
+            assert_eq!(keys(bytes).collect::<Vec<Key>>(), vec![key], "{:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn unknown_and_incomplete_sequences_are_skipped() {
+        assert_eq!(
+            keys(b"\x1b[1;5Ha\xffb\x1b[1").collect::<Vec<Key>>(),
+            vec![Key::Char('a'), Key::Char('\u{fffd}'), Key::Char('b')]
+        );
+    }
+}
@@ -3,6 +3,9 @@ use std::io;
 use buffer::Cell;
 use layout::Rect;
 
+mod input;
+pub use self::input::{keys, Key, Keys};
+
 #[cfg(feature = "rustbox")]
 mod rustbox;
 #[cfg(feature = "rustbox")]
@@ -13,12 +16,20 @@ mod termion;
 #[cfg(feature = "termion")]
 pub use self::termion::{MouseBackend, RawBackend, TermionBackend, AlternateScreenBackend};
 
+mod test;
+pub use self::test::TestBackend;
+
 pub trait Backend {
     fn draw<'a, I>(&mut self, content: I) -> Result<(), io::Error>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>;
     fn hide_cursor(&mut self) -> Result<(), io::Error>;
     fn show_cursor(&mut self) -> Result<(), io::Error>;
+    /// Moves the cursor to the given cell. Does nothing by default, for the backends which
+    /// cannot move it.
+    fn set_cursor(&mut self, _x: u16, _y: u16) -> Result<(), io::Error> {
+        Ok(())
+    }
     fn clear(&mut self) -> Result<(), io::Error>;
     fn size(&self) -> Result<Rect, io::Error>;
     fn flush(&mut self) -> Result<(), io::Error>;
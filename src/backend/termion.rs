@@ -17,6 +17,9 @@ where
     stdout: W,
 }
 
+/// Ends the OSC 8 hyperlink being written
+const OSC8_CLOSE: &str = "\u{1b}]8;;\u{1b}\\";
+
 pub type RawBackend = TermionBackend<termion::raw::RawTerminal<io::Stdout>>;
 pub type MouseBackend =
     TermionBackend<termion::input::MouseTerminal<termion::raw::RawTerminal<io::Stdout>>>;
@@ -53,6 +56,11 @@ where
     pub fn with_stdout(stdout: W) -> TermionBackend<W> {
         TermionBackend { stdout }
     }
+
+    /// Returns a reference to the writer the backend outputs to
+    pub fn get_ref(&self) -> &W {
+        &self.stdout
+    }
 }
 
 impl<W> Write for TermionBackend<W>
@@ -91,15 +99,24 @@ where
         self.stdout.flush()
     }
 
+    /// Moves the cursor to the given cell
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        write!(self.stdout, "{}", termion::cursor::Goto(x + 1, y + 1))?;
+        self.stdout.flush()
+    }
+
     fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
         let mut string = String::with_capacity(content.size_hint().0 * 3);
+        // The terminal is reset at the end of each draw call. From there, each change of color,
+        // including back to `Color::Reset`, is written explicitly.
         let mut style = Style::default();
         let mut last_y = 0;
         let mut last_x = 0;
         let mut inst = 0;
+        let mut link: Option<&str> = None;
         for (x, y, cell) in content {
             if y != last_y || x != last_x + 1 {
                 string.push_str(&format!("{}", termion::cursor::Goto(x + 1, y + 1)));
@@ -108,34 +125,55 @@ where
             last_x = x;
             last_y = y;
             if cell.style.modifier != style.modifier {
-                string.push_str(&cell.style.modifier.termion_modifier());
-                style.modifier = cell.style.modifier;
-                if style.modifier == Modifier::Reset {
-                    style.bg = Color::Reset;
-                    style.fg = Color::Reset;
+                // Modifiers add up on the terminal, the previous one has to be reset first, which
+                // also resets the colors
+                if style.modifier != Modifier::Reset {
+                    string.push_str(&Modifier::Reset.termion_modifier());
+                    style = Style::default();
+                    inst += 1;
+                }
+                if cell.style.modifier != Modifier::Reset {
+                    string.push_str(&cell.style.modifier.termion_modifier());
+                    style.modifier = cell.style.modifier;
+                    inst += 1;
                 }
-                inst += 1;
             }
             if cell.style.fg != style.fg {
-                string.push_str(&cell.style.fg.termion_fg());
+                string.push_str(&cell.style.fg.to_fg_ansi());
                 style.fg = cell.style.fg;
                 inst += 1;
             }
             if cell.style.bg != style.bg {
-                string.push_str(&cell.style.bg.termion_bg());
+                string.push_str(&cell.style.bg.to_bg_ansi());
                 style.bg = cell.style.bg;
                 inst += 1;
             }
+            if cell.link.as_deref() != link {
+                // A single hyperlink is opened for each run of cells sharing the same URL
+                if link.is_some() {
+                    string.push_str(OSC8_CLOSE);
+                    inst += 1;
+                }
+                link = cell.link.as_deref();
+                if let Some(url) = link {
+                    string.push_str(&format!("\u{1b}]8;;{}\u{1b}\\", url));
+                    inst += 1;
+                }
+            }
             string.push_str(&cell.symbol);
             inst += 1;
         }
+        if link.is_some() {
+            string.push_str(OSC8_CLOSE);
+            inst += 1;
+        }
         debug!("{} instructions outputed.", inst);
         write!(
             self.stdout,
             "{}{}{}{}",
             string,
-            Color::Reset.termion_fg(),
-            Color::Reset.termion_bg(),
+            Color::Reset.to_fg_ansi(),
+            Color::Reset.to_bg_ansi(),
             Modifier::Reset.termion_modifier()
         )
     }
@@ -156,83 +194,12 @@ where
     }
 }
 
-macro_rules! termion_fg {
-    ($color:ident) => (
-        format!("{}", termion::color::Fg(termion::color::$color))
-    );
-}
-
-macro_rules! termion_fg_rgb {
-    ($r:expr, $g:expr, $b:expr) => (
-        format!("{}", termion::color::Fg(termion::color::Rgb($r, $g, $b)))
-    );
-}
-
-macro_rules! termion_bg {
-    ($color:ident) => (
-        format!("{}", termion::color::Bg(termion::color::$color))
-    );
-}
-
-macro_rules! termion_bg_rgb {
-    ($r:expr, $g:expr, $b:expr) => (
-        format!("{}", termion::color::Bg(termion::color::Rgb($r, $g, $b)))
-    );
-}
-
 macro_rules! termion_modifier {
     ($style:ident) => (
         format!("{}", termion::style::$style)
     );
 }
 
-impl Color {
-    pub fn termion_fg(&self) -> String {
-        match *self {
-            Color::Reset => termion_fg!(Reset),
-            Color::Black => termion_fg!(Black),
-            Color::Red => termion_fg!(Red),
-            Color::Green => termion_fg!(Green),
-            Color::Yellow => termion_fg!(Yellow),
-            Color::Blue => termion_fg!(Blue),
-            Color::Magenta => termion_fg!(Magenta),
-            Color::Cyan => termion_fg!(Cyan),
-            Color::Gray => termion_fg!(White),
-            Color::DarkGray => termion_fg!(LightBlack),
-            Color::LightRed => termion_fg!(LightRed),
-            Color::LightGreen => termion_fg!(LightGreen),
-            Color::LightBlue => termion_fg!(LightBlue),
-            Color::LightYellow => termion_fg!(LightYellow),
-            Color::LightMagenta => termion_fg!(LightMagenta),
-            Color::LightCyan => termion_fg!(LightCyan),
-            Color::White => termion_fg!(LightWhite),
-            Color::Rgb(r, g, b) => termion_fg_rgb!(r, g, b),
-        }
-    }
-    pub fn termion_bg(&self) -> String {
-        match *self {
-            Color::Reset => termion_bg!(Reset),
-            Color::Black => termion_bg!(Black),
-            Color::Red => termion_bg!(Red),
-            Color::Green => termion_bg!(Green),
-            Color::Yellow => termion_bg!(Yellow),
-            Color::Blue => termion_bg!(Blue),
-            Color::Magenta => termion_bg!(Magenta),
-            Color::Cyan => termion_bg!(Cyan),
-            Color::Gray => termion_bg!(White),
-            Color::DarkGray => termion_bg!(LightBlack),
-            Color::LightRed => termion_bg!(LightRed),
-            Color::LightGreen => termion_bg!(LightGreen),
-            Color::LightBlue => termion_bg!(LightBlue),
-            Color::LightYellow => termion_bg!(LightYellow),
-            Color::LightMagenta => termion_bg!(LightMagenta),
-            Color::LightCyan => termion_bg!(LightCyan),
-            Color::White => termion_bg!(LightWhite),
-            Color::Rgb(r, g, b) => termion_bg_rgb!(r, g, b),
-        }
-    }
-}
-
 impl Modifier {
     pub fn termion_modifier(&self) -> String {
         match *self {
@@ -255,3 +222,69 @@ impl Modifier {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(cells: &[(u16, u16, Cell)]) -> String {
+        let mut backend = TermionBackend::with_stdout(Vec::new());
+        backend
+            .draw(cells.iter().map(|&(x, y, ref cell)| (x, y, cell)))
+            .unwrap();
+        String::from_utf8(backend.stdout).unwrap()
+    }
+
+    fn cell(symbol: &str, style: Style) -> Cell {
+        Cell {
+            symbol: symbol.into(),
+            style,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn colors_going_back_to_reset_are_written() {
+        let red = Style::default().fg(Color::Red).bg(Color::Red);
+        let output = draw(&[(0, 0, cell("a", red)), (1, 0, cell("b", Style::default()))]);
+        let b = output.find('b').unwrap();
+        let a = output.find('a').unwrap();
+        assert!(output[a..b].contains(&Color::Reset.to_fg_ansi()), "{:?}", output);
+        assert!(output[a..b].contains(&Color::Reset.to_bg_ansi()), "{:?}", output);
+    }
+
+    #[test]
+    fn previous_modifiers_are_reset() {
+        let bold = Style::default().fg(Color::Red).modifier(Modifier::Bold);
+        let italic = Style::default().fg(Color::Red).modifier(Modifier::Italic);
+        let output = draw(&[(0, 0, cell("a", bold)), (1, 0, cell("b", italic))]);
+        let expected = format!(
+            "{}{}{}b",
+            Modifier::Reset.termion_modifier(),
+            Modifier::Italic.termion_modifier(),
+            Color::Red.to_fg_ansi()
+        );
+        assert!(output.contains(&expected), "{:?}", output);
+    }
+
+    #[test]
+    fn runs_of_linked_cells_are_wrapped_in_a_single_hyperlink() {
+        let mut a = cell("a", Style::default());
+        a.set_link(Some("https://example.com"));
+        let mut b = a.clone();
+        b.set_symbol("b");
+        let c = cell("c", Style::default());
+        let output = draw(&[(0, 0, a), (1, 0, b), (2, 0, c)]);
+        assert!(
+            output.contains("\u{1b}]8;;https://example.com\u{1b}\\ab\u{1b}]8;;\u{1b}\\c"),
+            "{:?}",
+            output
+        );
+
+        // A link reaching the last cell drawn is closed as well
+        let mut d = cell("d", Style::default());
+        d.set_link(Some("https://example.com"));
+        let output = draw(&[(0, 0, d)]);
+        assert!(output.contains("d\u{1b}]8;;\u{1b}\\"), "{:?}", output);
+    }
+}
@@ -0,0 +1,147 @@
+use std::io;
+
+use super::Backend;
+#[cfg(feature = "termion")]
+use super::TermionBackend;
+use buffer::Cell;
+use layout::Rect;
+
+/// A backend with a fixed size recording the cells drawn to it, to test what is output by a draw
+/// call. With the `termion` feature, the exact bytes (characters and escape sequences) that
+/// would be sent to a terminal by the termion backend are captured as well and returned by
+/// [`buffer`], which only exists with this feature. It may also be used to render widgets without
+/// any actual terminal.
+///
+/// [`buffer`]: #method.buffer
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::Terminal;
+/// # use tui::backend::TestBackend;
+/// # use tui::layout::Rect;
+/// # fn main() {
+/// let mut terminal = Terminal::new(TestBackend::new(Rect::new(0, 0, 10, 2))).unwrap();
+/// terminal.current_buffer_mut().get_mut(3, 1).set_symbol("x");
+/// terminal.draw().unwrap();
+/// assert_eq!(terminal.backend().drawn(), &[(3, 1)]);
+/// # }
+/// ```
+pub struct TestBackend {
+    size: Rect,
+    /// Position of the cells drawn so far
+    drawn: Vec<(u16, u16)>,
+    #[cfg(feature = "termion")]
+    output: TermionBackend<Vec<u8>>,
+}
+
+impl TestBackend {
+    pub fn new(size: Rect) -> TestBackend {
+        TestBackend {
+            size,
+            drawn: Vec::new(),
+            #[cfg(feature = "termion")]
+            output: TermionBackend::with_stdout(Vec::new()),
+        }
+    }
+
+    /// Returns the position of the cells drawn so far, in the order they were drawn
+    pub fn drawn(&self) -> &[(u16, u16)] {
+        &self.drawn
+    }
+
+    /// Forgets the cells drawn so far, e.g. to only check what the next draw call outputs
+    pub fn clear_drawn(&mut self) {
+        self.drawn.clear();
+    }
+
+    /// Returns all the bytes output so far. This method is only available with the `termion`
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::Terminal;
+    /// # use tui::backend::TestBackend;
+    /// # use tui::layout::Rect;
+    /// # fn main() {
+    /// let mut terminal = Terminal::new(TestBackend::new(Rect::new(0, 0, 10, 2))).unwrap();
+    /// terminal.current_buffer_mut().get_mut(3, 1).set_symbol("x");
+    /// terminal.draw().unwrap();
+    /// assert!(terminal.backend().buffer().starts_with(b"\x1b[2;4Hx"));
+    /// # }
+    /// ```
+    #[cfg(feature = "termion")]
+    pub fn buffer(&self) -> &[u8] {
+        self.output.get_ref()
+    }
+}
+
+impl Backend for TestBackend {
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), io::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let content = content.collect::<Vec<(u16, u16, &Cell)>>();
+        self.drawn.extend(content.iter().map(|&(x, y, _)| (x, y)));
+        #[cfg(feature = "termion")]
+        self.output.draw(content.into_iter())?;
+        Ok(())
+    }
+    fn hide_cursor(&mut self) -> Result<(), io::Error> {
+        #[cfg(feature = "termion")]
+        self.output.hide_cursor()?;
+        Ok(())
+    }
+    fn show_cursor(&mut self) -> Result<(), io::Error> {
+        #[cfg(feature = "termion")]
+        self.output.show_cursor()?;
+        Ok(())
+    }
+    fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), io::Error> {
+        #[cfg(feature = "termion")]
+        self.output.set_cursor(x, y)?;
+        #[cfg(not(feature = "termion"))]
+        let _ = (x, y);
+        Ok(())
+    }
+    fn clear(&mut self) -> Result<(), io::Error> {
+        #[cfg(feature = "termion")]
+        self.output.clear()?;
+        Ok(())
+    }
+    fn size(&self) -> Result<Rect, io::Error> {
+        Ok(self.size)
+    }
+    fn flush(&mut self) -> Result<(), io::Error> {
+        #[cfg(feature = "termion")]
+        Backend::flush(&mut self.output)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use style::{Color, Style};
+    use terminal::Terminal;
+
+    #[test]
+    fn drawn_cells_are_captured_with_their_escape_sequences() {
+        let mut terminal = Terminal::new(TestBackend::new(Rect::new(0, 0, 5, 5))).unwrap();
+        terminal
+            .current_buffer_mut()
+            .get_mut(2, 3)
+            .set_symbol("x")
+            .set_style(Style::default().fg(Color::Red));
+        terminal.draw().unwrap();
+        assert_eq!(terminal.backend().drawn(), &[(2, 3)]);
+        #[cfg(feature = "termion")]
+        assert_eq!(
+            String::from_utf8_lossy(terminal.backend().buffer()),
+            "\u{1b}[4;3H\u{1b}[31mx\u{1b}[39m\u{1b}[49m\u{1b}[m"
+        );
+    }
+}
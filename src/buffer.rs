@@ -1,9 +1,11 @@
-use std::cmp::min;
+use std::cmp::{max, min};
+use std::fmt;
 use std::usize;
 
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use layout::Rect;
+use layout::{Alignment, Rect};
 use style::{Color, Modifier, Style};
 
 /// A buffer cell
@@ -11,6 +13,8 @@ use style::{Color, Modifier, Style};
 pub struct Cell {
     pub symbol: String,
     pub style: Style,
+    /// URL the cell links to, output as an OSC 8 hyperlink by the backends supporting them
+    pub link: Option<String>,
 }
 
 impl Cell {
@@ -46,10 +50,18 @@ impl Cell {
         self
     }
 
+    /// Make the cell part of a hyperlink to the given URL, or of none. Consecutive cells linking
+    /// to the same URL form a single clickable link.
+    pub fn set_link(&mut self, link: Option<&str>) -> &mut Cell {
+        self.link = link.map(String::from);
+        self
+    }
+
     pub fn reset(&mut self) {
         self.symbol.clear();
         self.symbol.push(' ');
         self.style.reset();
+        self.link = None;
     }
 }
 
@@ -58,10 +70,35 @@ impl Default for Cell {
         Cell {
             symbol: " ".into(),
             style: Default::default(),
+            link: None,
         }
     }
 }
 
+/// Iterator over the cells of a buffer and their coordinates, see `Buffer::iter`
+pub struct Cells<'a> {
+    buffer: &'a Buffer,
+    index: usize,
+}
+
+impl<'a> Iterator for Cells<'a> {
+    type Item = (u16, u16, &'a Cell);
+
+    fn next(&mut self) -> Option<(u16, u16, &'a Cell)> {
+        let cell = self.buffer.content.get(self.index)?;
+        let width = self.buffer.area.width as usize;
+        let x = self.buffer.area.x + (self.index % width) as u16;
+        let y = self.buffer.area.y + (self.index / width) as u16;
+        self.index += 1;
+        Some((x, y, cell))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.buffer.content.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
 /// A buffer that maps to the desired content of the terminal after the draw call
 ///
 /// No widget in the library interacts directly with the terminal. Instead each of them is required
@@ -88,7 +125,9 @@ impl Default for Cell {
 ///         fg: Color::Red,
 ///         bg: Color::White,
 ///         modifier: Modifier::Reset
-///     }});
+///     },
+///     link: None,
+/// });
 /// buf.get_mut(5, 0).set_char('x');
 /// assert_eq!(buf.get(5, 0).symbol, "x");
 /// # }
@@ -100,6 +139,8 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// The cell the buffer is cleared with
+    blank: Cell,
 }
 
 impl Default for Buffer {
@@ -107,6 +148,7 @@ impl Default for Buffer {
         Buffer {
             area: Default::default(),
             content: Vec::new(),
+            blank: Cell::default(),
         }
     }
 }
@@ -118,6 +160,15 @@ impl Buffer {
         Buffer::filled(area, &cell)
     }
 
+    /// Returns a Buffer with all cells blank and set to the given style, e.g. to draw widgets on
+    /// top of a themed background
+    pub fn empty_styled(area: Rect, style: Style) -> Buffer {
+        let mut buffer = Buffer::empty(area);
+        buffer.set_blank(" ", style);
+        buffer.reset();
+        buffer
+    }
+
     /// Returns a Buffer with all cells initialized with the attributes of the given Cell
     pub fn filled(area: Rect, cell: &Cell) -> Buffer {
         let size = area.area() as usize;
@@ -128,9 +179,36 @@ impl Buffer {
         Buffer {
             area: area,
             content: content,
+            blank: Cell::default(),
         }
     }
 
+    /// Set the cell the buffer is cleared with by `reset` and the new cells are filled with when
+    /// it grows, e.g. `░` to draw the widgets onto a textured background. It is a blank space of
+    /// the default style unless the buffer was created by `empty_styled`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+    /// buf.set_blank("░", Style::default());
+    /// buf.resize(Rect::new(0, 0, 3, 1));
+    /// assert_eq!(buf.to_string(), "  ░");
+    /// buf.reset();
+    /// assert_eq!(buf.to_string(), "░░░");
+    /// ```
+    pub fn set_blank(&mut self, symbol: &str, style: Style) {
+        self.blank.set_symbol(symbol).set_style(style);
+    }
+
+    /// Returns the cell the buffer is cleared with
+    pub fn blank(&self) -> &Cell {
+        &self.blank
+    }
+
     /// Returns the content of the buffer as a slice
     pub fn content(&self) -> &[Cell] {
         &self.content
@@ -141,6 +219,25 @@ impl Buffer {
         &self.area
     }
 
+    /// Returns an iterator over the cells of the buffer and their global coordinates, row after
+    /// row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// let buffer = Buffer::empty(Rect::new(5, 2, 2, 1));
+    /// let coords = buffer.iter().map(|(x, y, _)| (x, y)).collect::<Vec<(u16, u16)>>();
+    /// assert_eq!(coords, vec![(5, 2), (6, 2)]);
+    /// ```
+    pub fn iter<'b>(&'b self) -> Cells<'b> {
+        Cells {
+            buffer: self,
+            index: 0,
+        }
+    }
+
     /// Returns a reference to Cell at the given coordinates
     pub fn get(&self, x: u16, y: u16) -> &Cell {
         let i = self.index_of(x, y);
@@ -193,6 +290,29 @@ impl Buffer {
         ((y - self.area.y) * self.area.width + (x - self.area.x)) as usize
     }
 
+    /// Returns the index in the Vec<Cell> for the given global (x, y) coordinates or `None` if
+    /// they lie outside of this Buffer's area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// let rect = Rect::new(200, 100, 10, 10);
+    /// let buffer = Buffer::empty(rect);
+    /// assert_eq!(buffer.checked_index_of(201, 101), Some(11));
+    /// assert_eq!(buffer.checked_index_of(0, 0), None);
+    /// ```
+    pub fn checked_index_of(&self, x: u16, y: u16) -> Option<usize> {
+        if x >= self.area.left() && x < self.area.right() && y >= self.area.top()
+            && y < self.area.bottom()
+        {
+            Some(self.index_of(x, y))
+        } else {
+            None
+        }
+    }
+
     /// Returns the (global) coordinates of a cell given its index
     ///
     /// Global coordinates are offset by the Buffer's area offset (`x`/`y`).
@@ -238,17 +358,138 @@ impl Buffer {
         self.set_stringn(x, y, string, usize::MAX, style);
     }
 
-    /// Print at most the first n characters of a string if enough space is available
-    /// until the end of the line
-    pub fn set_stringn(&mut self, x: u16, y: u16, string: &str, limit: usize, style: &Style) {
-        let mut index = self.index_of(x, y);
-        let graphemes = UnicodeSegmentation::graphemes(string, true).collect::<Vec<&str>>();
-        let max_index = min((self.area.right() - x) as usize, limit);
-        for s in graphemes.into_iter().take(max_index) {
+    /// Print the string in at most n columns if enough space is available until the end of the
+    /// line. Wide characters take as many cells as their width, the cells they cover being left
+    /// empty, and characters which would be cut are not printed. Nothing is printed if the
+    /// position is outside of the buffer.
+    ///
+    /// Returns the number of columns written.
+    pub fn set_stringn(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: &str,
+        limit: usize,
+        style: &Style,
+    ) -> usize {
+        let mut index = match self.checked_index_of(x, y) {
+            Some(index) => index,
+            None => return 0,
+        };
+        let mut remaining = min((self.area.right() - x) as usize, limit);
+        let mut written = 0;
+        for s in UnicodeSegmentation::graphemes(string, true) {
+            let width = max(s.width(), 1);
+            if width > remaining {
+                break;
+            }
             self.content[index].symbol.clear();
             self.content[index].symbol.push_str(s);
             self.content[index].style = *style;
-            index += 1;
+            for cell in &mut self.content[index + 1..index + width] {
+                cell.symbol.clear();
+                cell.style = *style;
+            }
+            index += width;
+            remaining -= width;
+            written += width;
+        }
+        written
+    }
+
+    /// Print the string aligned within the `width` columns starting at the given position, the
+    /// string being truncated like with `set_stringn` if it is too wide.
+    ///
+    /// Returns the number of columns written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::{Alignment, Rect};
+    /// # use tui::style::Style;
+    /// # fn main() {
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+    /// let written = buf.set_stringn_aligned(1, 0, "ab", 6, Alignment::Right, &Style::default());
+    /// assert_eq!(written, 2);
+    /// assert_eq!(buf.to_string(), "     ab ");
+    /// # }
+    /// ```
+    pub fn set_stringn_aligned(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: &str,
+        width: usize,
+        alignment: Alignment,
+        style: &Style,
+    ) -> usize {
+        let fitting = UnicodeSegmentation::graphemes(string, true)
+            .map(|g| max(g.width(), 1))
+            .scan(0, |total, w| {
+                *total += w;
+                Some(*total)
+            })
+            .take_while(|&total| total <= width)
+            .last()
+            .unwrap_or(0);
+        let offset = alignment.offset(fitting as u16, width.min(usize::from(u16::MAX)) as u16);
+        self.set_stringn(x.saturating_add(offset), y, string, fitting, style)
+    }
+
+    /// Print a right-to-left string in the `width` columns starting at the given position: its
+    /// first grapheme is placed against the right edge and the following ones on its left. The
+    /// string is truncated like with `set_stringn` if it is too wide.
+    ///
+    /// Returns the number of columns written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// # fn main() {
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+    /// buf.set_stringn_rtl(0, 0, "שלום", 6, &Style::default());
+    /// assert_eq!(buf.to_string(), "  םולש");
+    /// # }
+    /// ```
+    pub fn set_stringn_rtl(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: &str,
+        width: usize,
+        style: &Style,
+    ) -> usize {
+        let mut fitting = 0;
+        let mut graphemes = Vec::new();
+        for g in UnicodeSegmentation::graphemes(string, true) {
+            fitting += max(g.width(), 1);
+            if fitting > width {
+                break;
+            }
+            graphemes.push(g);
+        }
+        let reversed = graphemes.into_iter().rev().collect::<String>();
+        self.set_stringn_aligned(x, y, &reversed, width, Alignment::Right, style)
+    }
+
+    /// Make all cells outside of the given area faint so that the content of this area stands
+    /// out, for example to highlight the focused widget or a popup.
+    ///
+    /// As a style holds a single modifier, the modifier previously set on the faded cells is
+    /// replaced.
+    pub fn dim_except(&mut self, area: &Rect) {
+        for (i, cell) in self.content.iter_mut().enumerate() {
+            let x = self.area.x + (i % self.area.width as usize) as u16;
+            let y = self.area.y + (i / self.area.width as usize) as u16;
+            if x < area.left() || x >= area.right() || y < area.top() || y >= area.bottom() {
+                cell.set_modifier(Modifier::Faint);
+            }
         }
     }
 
@@ -259,22 +500,77 @@ impl Buffer {
         if self.content.len() > length {
             self.content.truncate(length);
         } else {
-            self.content.resize(length, Default::default());
+            self.content.resize(length, self.blank.clone());
         }
         self.area = area;
     }
 
-    /// Reset all cells in the buffer
+    /// Set all the cells of the buffer lying in the given area to the given symbol and style,
+    /// e.g. to clear it onto a textured background
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+    /// buf.clear_with(&Rect::new(1, 1, 5, 5), "░", &Style::default());
+    /// assert_eq!(buf.to_string(), "    \n ░░░");
+    /// ```
+    pub fn clear_with(&mut self, area: &Rect, symbol: &str, style: &Style) {
+        let area = self.area.intersection(area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y)
+                    .set_symbol(symbol)
+                    .set_style(*style)
+                    .set_link(None);
+            }
+        }
+    }
+
+    /// Reset all cells in the buffer to its blank cell
     pub fn reset(&mut self) {
         for c in &mut self.content {
-            c.reset();
+            c.clone_from(&self.blank);
+        }
+    }
+
+    /// Copy all the cells of another buffer, blank ones included, so that its top left corner
+    /// lands on the given position, e.g. to draw a widget rendered once and cached. Unlike
+    /// `merge`, the buffer keeps its area and the cells falling outside of it are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// let mut cached = Buffer::empty(Rect::new(0, 0, 2, 1));
+    /// cached.set_string(0, 0, "ab", &Style::default());
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+    /// buf.set_string(0, 1, "xyz", &Style::default());
+    /// buf.blit(&cached, 2, 1);
+    /// assert_eq!(buf.to_string(), "   \nxya");
+    /// ```
+    pub fn blit(&mut self, src: &Buffer, dest_x: u16, dest_y: u16) {
+        for (x, y, cell) in src.iter() {
+            let x = u32::from(dest_x) + u32::from(x - src.area.x);
+            let y = u32::from(dest_y) + u32::from(y - src.area.y);
+            if x > u32::from(u16::MAX) || y > u32::from(u16::MAX) {
+                continue;
+            }
+            if let Some(i) = self.checked_index_of(x as u16, y as u16) {
+                self.content[i] = cell.clone();
+            }
         }
     }
 
     /// Merge an other buffer into this one
     pub fn merge(&mut self, other: &Buffer) {
         let area = self.area.union(&other.area);
-        let cell: Cell = Default::default();
+        let cell = self.blank.clone();
         self.content.resize(area.area() as usize, cell.clone());
 
         // Move original content to the appropriate space
@@ -306,10 +602,139 @@ impl Buffer {
     }
 }
 
+/// Returns the cells of a line of the buffer that should actually be output, the cells hidden by
+/// a preceding wide character being skipped.
+fn visible_cells(line: &[Cell]) -> Vec<&Cell> {
+    let mut cells = Vec::with_capacity(line.len());
+    let mut skip = 0;
+    for cell in line {
+        if skip > 0 {
+            skip -= 1;
+            continue;
+        }
+        skip = max(cell.symbol.width(), 1) - 1;
+        cells.push(cell);
+    }
+    cells
+}
+
+fn ansi_modifier(modifier: Modifier) -> &'static str {
+    match modifier {
+        Modifier::Blink => "5",
+        Modifier::Bold => "1",
+        Modifier::CrossedOut => "9",
+        Modifier::Faint => "2",
+        Modifier::Framed => "51",
+        Modifier::Invert => "7",
+        Modifier::Italic => "3",
+        Modifier::NoBlink => "25",
+        Modifier::NoBold => "21",
+        Modifier::NoCrossedOut => "29",
+        Modifier::NoFaint => "22",
+        Modifier::NoInvert => "27",
+        Modifier::NoItalic => "23",
+        Modifier::NoUnderline => "24",
+        Modifier::Reset => "0",
+        Modifier::Underline => "4",
+    }
+}
+
+impl Buffer {
+    /// Serializes the buffer row by row, the style of each cell being set using SGR escape
+    /// sequences. Each row ends with its style reset and rows are separated by a new line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::{Color, Style};
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+    /// buf.set_string(1, 0, "ab", &Style::default().fg(Color::Red));
+    /// assert_eq!(buf.to_ansi_string(), " \u{1b}[31mab\u{1b}[0m");
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        let mut string = String::with_capacity(self.content.len());
+        if self.area.width == 0 {
+            return string;
+        }
+        for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
+            if y != 0 {
+                string.push('\n');
+            }
+            let mut style = Style::default();
+            for cell in visible_cells(line) {
+                let mut codes = Vec::new();
+                if cell.style.modifier != style.modifier {
+                    // Modifiers add up, the previous one has to be reset first, which also resets
+                    // the colors
+                    if style.modifier != Modifier::Reset {
+                        codes.push(String::from(ansi_modifier(Modifier::Reset)));
+                        style = Style::default();
+                    }
+                    if cell.style.modifier != Modifier::Reset {
+                        codes.push(String::from(ansi_modifier(cell.style.modifier)));
+                        style.modifier = cell.style.modifier;
+                    }
+                }
+                if cell.style.fg != style.fg {
+                    codes.push(cell.style.fg.fg_code());
+                    style.fg = cell.style.fg;
+                }
+                if cell.style.bg != style.bg {
+                    codes.push(cell.style.bg.bg_code());
+                    style.bg = cell.style.bg;
+                }
+                if !codes.is_empty() {
+                    string.push_str(&format!("\u{1b}[{}m", codes.join(";")));
+                }
+                string.push_str(&cell.symbol);
+            }
+            if style != Style::default() {
+                string.push_str("\u{1b}[0m");
+            }
+        }
+        string
+    }
+}
+
+/// Writes the symbols of the buffer row by row, ignoring their style. Rows are separated by a new
+/// line.
+impl fmt::Display for Buffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.area.width == 0 {
+            return Ok(());
+        }
+        for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in visible_cells(line) {
+                f.write_str(&cell.symbol)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn the_blank_cell_fills_the_cells_without_content() {
+        let mut buf = Buffer::empty_styled(Rect::new(0, 0, 2, 1), Style::default().bg(Color::Red));
+        assert_eq!(buf.blank().style.bg, Color::Red);
+        assert_eq!(buf.get(1, 0).style.bg, Color::Red);
+
+        buf.set_blank(".", Style::default());
+        buf.set_string(0, 0, "a", &Style::default());
+        buf.merge(&Buffer::empty(Rect::new(0, 0, 1, 2)));
+        assert_eq!(buf.to_string(), "  \n .");
+        buf.reset();
+        assert_eq!(buf.to_string(), "..\n..");
+    }
+
     #[test]
     fn it_translates_to_and_from_coordinates() {
         let rect = Rect::new(200, 100, 50, 80);
@@ -343,4 +768,157 @@ mod tests {
         // width is 10; zero-indexed means that 10 would be the 11th cell.
         buf.index_of(10, 0);
     }
+
+    #[test]
+    fn checked_index_of_is_none_out_of_bounds() {
+        let buf = Buffer::empty(Rect::new(5, 5, 10, 10));
+
+        assert_eq!(buf.checked_index_of(5, 5), Some(0));
+        assert_eq!(buf.checked_index_of(14, 14), Some(99));
+        assert_eq!(buf.checked_index_of(15, 5), None);
+        assert_eq!(buf.checked_index_of(5, 15), None);
+        assert_eq!(buf.checked_index_of(4, 5), None);
+        assert_eq!(buf.checked_index_of(5, 4), None);
+    }
+
+    #[test]
+    fn strings_are_printed_according_to_their_width() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buf.set_string(0, 0, "１月a", &Style::default());
+        let symbols = buf.content.iter().map(|c| c.symbol.as_str()).collect::<Vec<&str>>();
+        assert_eq!(symbols, vec!["１", "", "月", "", "a", " "]);
+
+        // Characters that do not fit are not printed
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buf.set_stringn(1, 0, "１月", 3, &Style::default());
+        assert_eq!(buf.to_string(), " １   ");
+    }
+
+    #[test]
+    fn cells_are_iterated_row_after_row() {
+        let mut buf = Buffer::empty(Rect::new(3, 7, 2, 2));
+        buf.set_string(3, 7, "ab", &Style::default());
+        buf.set_string(3, 8, "cd", &Style::default());
+        let cells = buf.iter()
+            .map(|(x, y, c)| (x, y, c.symbol.as_str()))
+            .collect::<Vec<(u16, u16, &str)>>();
+        assert_eq!(cells, vec![(3, 7, "a"), (4, 7, "b"), (3, 8, "c"), (4, 8, "d")]);
+        assert_eq!(buf.iter().size_hint(), (4, Some(4)));
+        assert_eq!(Buffer::empty(Rect::default()).iter().next(), None);
+    }
+
+    #[test]
+    fn blitted_buffers_are_copied_cell_by_cell() {
+        let mut cached = Buffer::empty(Rect::new(5, 5, 3, 3));
+        cached.set_string(5, 5, "abc", &Style::default().fg(Color::Red));
+        cached.set_string(5, 7, "g i", &Style::default());
+        let mut buf = Buffer::filled(
+            Rect::new(0, 0, 5, 4),
+            &Cell {
+                symbol: "░".into(),
+                style: Style::default(),
+                link: None,
+            },
+        );
+        buf.blit(&cached, 1, 2);
+        // The blank cells are copied too and the last row falls outside of the buffer
+        assert_eq!(buf.to_string(), "░░░░░\n░░░░░\n░abc░\n░   ░");
+        assert_eq!(buf.get(2, 2), cached.get(6, 5));
+    }
+
+    #[test]
+    fn cleared_areas_are_filled_with_the_given_symbol() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        buf.set_string(0, 0, "abcd", &Style::default());
+        let style = Style::default().fg(Color::DarkGray);
+        buf.clear_with(&Rect::new(1, 0, 2, 2), "░", &style);
+        assert_eq!(buf.to_string(), "a░░d\n ░░ \n    ");
+        assert_eq!(buf.get(2, 1).style, style);
+        assert_eq!(buf.get(0, 1), &Cell::default());
+    }
+
+    #[test]
+    fn wide_characters_straddling_the_limit_are_dropped() {
+        let style = Style::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        assert_eq!(buf.set_stringn(0, 0, "a１月", 4, &style), 3);
+        assert_eq!(buf.to_string(), "a１   ");
+
+        // The end of the line limits the string too
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        assert_eq!(buf.set_stringn(3, 0, "１月", 10, &style), 2);
+        assert_eq!(buf.to_string(), "   １ ");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        assert_eq!(buf.set_stringn(6, 0, "ab", 2, &style), 0);
+        assert_eq!(buf.set_stringn(0, 1, "ab", 2, &style), 0);
+        assert_eq!(buf.to_string(), "      ");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let written = buf.set_stringn_aligned(0, 0, "a１月", 4, Alignment::Right, &style);
+        assert_eq!(written, 3);
+        assert_eq!(buf.to_string(), " a１  ");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let written = buf.set_stringn_aligned(0, 0, "１月", 5, Alignment::Center, &style);
+        assert_eq!(written, 4);
+        assert_eq!(buf.to_string(), "１月  ");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buf.set_stringn_aligned(0, 0, "１", 5, Alignment::Center, &style);
+        assert_eq!(buf.to_string(), " １   ");
+    }
+
+    #[test]
+    fn cells_outside_of_the_focused_area_are_dimmed() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        buf.get_mut(1, 1).set_modifier(Modifier::Bold);
+        buf.dim_except(&Rect::new(1, 1, 2, 2));
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let modifier = buf.get(x, y).style.modifier;
+                match (x, y) {
+                    (1, 1) => assert_eq!(modifier, Modifier::Bold),
+                    (1..=2, 1..=2) => assert_eq!(modifier, Modifier::Reset),
+                    _ => assert_eq!(modifier, Modifier::Faint),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_styled_buffers_are_filled_with_their_style() {
+        let style = Style::default().fg(Color::White).bg(Color::Blue);
+        let buf = Buffer::empty_styled(Rect::new(2, 3, 4, 5), style);
+        assert_eq!(buf.content.len(), 20);
+        assert!(buf.content.iter().all(|c| c.symbol == " " && c.style == style));
+    }
+
+    #[test]
+    fn it_serializes_to_strings() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buf.set_string(0, 0, "ab", &Style::default().fg(Color::Red).bg(Color::Rgb(1, 2, 3)));
+        buf.set_string(2, 0, "c", &Style::default().modifier(Modifier::Bold));
+        buf.get_mut(0, 1).set_symbol("コ");
+        buf.get_mut(2, 1).set_symbol("d").set_bg(Color::Blue);
+
+        assert_eq!(buf.to_string(), "abc \nコd ");
+        assert_eq!(
+            buf.to_ansi_string(),
+            "\u{1b}[31;48;2;1;2;3mab\u{1b}[1;39;49mc\u{1b}[0m \n\
+             コ\u{1b}[44md\u{1b}[49m "
+        );
+    }
+
+    #[test]
+    fn previous_modifiers_are_reset_when_serializing() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let red = Style::default().fg(Color::Red);
+        buf.set_string(0, 0, "a", &red.modifier(Modifier::Bold));
+        buf.set_string(1, 0, "b", &red.modifier(Modifier::Italic));
+        buf.set_string(2, 0, "c", &red);
+        assert_eq!(
+            buf.to_ansi_string(),
+            "\u{1b}[1;31ma\u{1b}[0;3;31mb\u{1b}[0;31mc\u{1b}[39m "
+        );
+    }
 }
@@ -0,0 +1,110 @@
+//! Hit-testing of the cells of the screen, to find out which part of the interface has been
+//! clicked.
+//!
+//! The regions are registered while the interface is drawn and the map is queried when a mouse
+//! event is received.
+//!
+//! # Examples
+//!
+//! ```
+//! # extern crate tui;
+//! # use tui::hitmap::HitMap;
+//! # use tui::layout::Rect;
+//! # use tui::widgets::Tabs;
+//! # fn main() {
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Target {
+//!     Tab(usize),
+//!     Quit,
+//! }
+//!
+//! let mut hits = HitMap::new();
+//! hits.register(Rect::new(30, 0, 6, 1), Target::Quit);
+//! let tabs_area = Rect::new(0, 0, 30, 1);
+//! let mut tabs = Tabs::default();
+//! tabs.titles(&["Tab1", "Tab2"]);
+//! for (i, area) in tabs.regions(&tabs_area).into_iter().enumerate() {
+//!     hits.register(area, Target::Tab(i));
+//! }
+//! assert_eq!(hits.at(8, 0), Some(Target::Tab(1)));
+//! assert_eq!(hits.at(32, 0), Some(Target::Quit));
+//! assert_eq!(hits.at(32, 1), None);
+//! # }
+//! ```
+
+use layout::Rect;
+
+/// The regions of the screen associated to some identifiers
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitMap<T> {
+    regions: Vec<(Rect, T)>,
+}
+
+impl<T> Default for HitMap<T> {
+    fn default() -> HitMap<T> {
+        HitMap {
+            regions: Vec::new(),
+        }
+    }
+}
+
+impl<T> HitMap<T> {
+    pub fn new() -> HitMap<T> {
+        HitMap::default()
+    }
+
+    /// Associates the cells of the given area to an identifier. When regions overlap, the last
+    /// one registered wins, as does the last widget drawn.
+    pub fn register(&mut self, area: Rect, id: T) {
+        self.regions.push((area, id));
+    }
+
+    /// Forgets all the regions, typically before drawing a new frame
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Returns the identifier of the region containing the given cell
+    pub fn at(&self, x: u16, y: u16) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.regions
+            .iter()
+            .rev()
+            .find(|&(area, _)| {
+                x >= area.left() && x < area.right() && y >= area.top() && y < area.bottom()
+            })
+            .map(|(_, id)| id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use widgets::Tabs;
+
+    #[test]
+    fn clicks_are_resolved_to_the_tab_below_them() {
+        let area = Rect::new(2, 1, 20, 1);
+        let mut tabs = Tabs::default();
+        tabs.titles(&["ab", "cde"]);
+        let regions = tabs.regions(&area);
+        assert_eq!(regions, vec![Rect::new(2, 1, 4, 1), Rect::new(7, 1, 5, 1)]);
+
+        let mut hits = HitMap::new();
+        for (i, region) in regions.into_iter().enumerate() {
+            hits.register(region, i);
+        }
+        assert_eq!(hits.at(1, 1), None);
+        assert_eq!(hits.at(3, 1), Some(0));
+        assert_eq!(hits.at(6, 1), None);
+        assert_eq!(hits.at(11, 1), Some(1));
+        assert_eq!(hits.at(11, 2), None);
+
+        hits.register(Rect::new(0, 0, 4, 4), 9);
+        assert_eq!(hits.at(3, 1), Some(9));
+        hits.clear();
+        assert_eq!(hits.at(3, 1), None);
+    }
+}
@@ -14,6 +14,37 @@ pub enum Direction {
     Vertical,
 }
 
+/// Horizontal alignment of some content within an area
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    /// Returns the offset at which content of the given width should start to be aligned within
+    /// the available width.
+    pub(crate) fn offset(&self, width: u16, available: u16) -> u16 {
+        match *self {
+            Alignment::Left => 0,
+            Alignment::Center => available.saturating_sub(width) / 2,
+            Alignment::Right => available.saturating_sub(width),
+        }
+    }
+}
+
+/// Direction in which the graphemes of a text are laid out
+///
+/// Only whole runs of text are supported: a right-to-left text is drawn from the right edge of
+/// its area with its graphemes in reversed order, without applying the Unicode bidirectional
+/// algorithm to the runs of different directions it may contain.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
 /// A simple rectangle used in the computation of the layout and to give widgets an hint about the
 /// area they are supposed to render to.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -45,35 +76,51 @@ impl Rect {
         }
     }
 
-    pub fn area(&self) -> u16 {
-        self.width * self.height
+    /// Returns a copy of this rectangle with the given width
+    pub fn with_width(mut self, width: u16) -> Rect {
+        self.width = width;
+        self
+    }
+
+    /// Returns a copy of this rectangle with the given height
+    pub fn with_height(mut self, height: u16) -> Rect {
+        self.height = height;
+        self
+    }
+
+    /// Returns the number of cells covered by the rectangle
+    pub fn area(&self) -> u32 {
+        u32::from(self.width) * u32::from(self.height)
     }
 
     pub fn left(&self) -> u16 {
         self.x
     }
 
+    /// Returns the column following the last one of the rectangle, saturating at `u16::MAX`
     pub fn right(&self) -> u16 {
-        self.x + self.width
+        self.x.saturating_add(self.width)
     }
 
     pub fn top(&self) -> u16 {
         self.y
     }
 
+    /// Returns the row following the last one of the rectangle, saturating at `u16::MAX`
     pub fn bottom(&self) -> u16 {
-        self.y + self.height
+        self.y.saturating_add(self.height)
     }
 
     pub fn inner(&self, margin: u16) -> Rect {
-        if self.width < 2 * margin || self.height < 2 * margin {
+        let margins = margin.saturating_mul(2);
+        if self.width < margins || self.height < margins {
             Rect::default()
         } else {
             Rect {
-                x: self.x + margin,
-                y: self.y + margin,
-                width: self.width - 2 * margin,
-                height: self.height - 2 * margin,
+                x: self.x.saturating_add(margin),
+                y: self.y.saturating_add(margin),
+                width: self.width - margins,
+                height: self.height - margins,
             }
         }
     }
@@ -81,8 +128,8 @@ impl Rect {
     pub fn union(&self, other: &Rect) -> Rect {
         let x1 = min(self.x, other.x);
         let y1 = min(self.y, other.y);
-        let x2 = max(self.x + self.width, other.x + other.width);
-        let y2 = max(self.y + self.height, other.y + other.height);
+        let x2 = max(self.right(), other.right());
+        let y2 = max(self.bottom(), other.bottom());
         Rect {
             x: x1,
             y: y1,
@@ -91,22 +138,30 @@ impl Rect {
         }
     }
 
+    /// Returns the area covered by both rectangles, which is empty if they do not intersect
     pub fn intersection(&self, other: &Rect) -> Rect {
         let x1 = max(self.x, other.x);
         let y1 = max(self.y, other.y);
-        let x2 = min(self.x + self.width, other.x + other.width);
-        let y2 = min(self.y + self.height, other.y + other.height);
+        let x2 = min(self.right(), other.right());
+        let y2 = min(self.bottom(), other.bottom());
         Rect {
             x: x1,
             y: y1,
-            width: x2 - x1,
-            height: y2 - y1,
+            width: x2.saturating_sub(x1),
+            height: y2.saturating_sub(y1),
         }
     }
 
     pub fn intersects(&self, other: &Rect) -> bool {
-        self.x < other.x + other.width && self.x + self.width > other.x
-            && self.y < other.y + other.height && self.y + self.height > other.y
+        self.x < other.right() && self.right() > other.x && self.y < other.bottom()
+            && self.bottom() > other.y
+    }
+}
+
+/// Builds a rectangle from a `(x, y, width, height)` tuple
+impl From<(u16, u16, u16, u16)> for Rect {
+    fn from((x, y, width, height): (u16, u16, u16, u16)) -> Rect {
+        Rect::new(x, y, width, height)
     }
 }
 
@@ -118,6 +173,50 @@ pub enum Size {
     Min(u16),
 }
 
+/// Splits an area into a grid of `rows` by `cols` cells of equal sizes, separated by `gutter`
+/// blank rows and columns. The cells are returned row after row. When the area cannot be divided
+/// evenly, the first rows and columns are one cell larger than the others.
+///
+/// # Examples
+/// ```
+/// # extern crate tui;
+/// # use tui::layout::{grid, Rect};
+///
+/// # fn main() {
+///     let cells = grid(&Rect::new(0, 0, 11, 4), 2, 2, 1);
+///     assert_eq!(cells, vec![Rect::new(0, 0, 5, 2), Rect::new(6, 0, 5, 2),
+///                            Rect::new(0, 3, 5, 1), Rect::new(6, 3, 5, 1)])
+/// # }
+///
+/// ```
+pub fn grid(area: &Rect, rows: u16, cols: u16, gutter: u16) -> Vec<Rect> {
+    let ys = grid_tracks(area.top(), area.bottom() - area.top(), rows, gutter);
+    let xs = grid_tracks(area.left(), area.right() - area.left(), cols, gutter);
+    ys.iter()
+        .flat_map(|&(y, height)| xs.iter().map(move |&(x, width)| Rect::new(x, y, width, height)))
+        .collect()
+}
+
+/// Returns the start and the length of `count` tracks of equal lengths separated by `gutter`
+/// cells along the given length
+fn grid_tracks(start: u16, length: u16, count: u16, gutter: u16) -> Vec<(u16, u16)> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let gutters = u32::from(gutter) * u32::from(count - 1);
+    let available = u32::from(length).saturating_sub(gutters);
+    let (size, remainder) = (available / u32::from(count), available % u32::from(count));
+    let mut position = u32::from(start);
+    (0..u32::from(count))
+        .map(|i| {
+            let size = size + if i < remainder { 1 } else { 0 };
+            let track = (position.min(u32::from(start) + u32::from(length)) as u16, size as u16);
+            position += size + u32::from(gutter);
+            track
+        })
+        .collect()
+}
+
 /// Wrapper function around the cassowary-rs solver to be able to split a given
 /// area into smaller ones based on the preferred widths or heights and the direction.
 ///
@@ -144,7 +243,10 @@ pub fn split(area: &Rect, dir: &Direction, margin: u16, sizes: &[Size]) -> Vec<R
         .map(|_| Element::new())
         .collect::<Vec<Element>>();
     let mut results = sizes.iter().map(|_| Rect::default()).collect::<Vec<Rect>>();
-    let dest_area = area.inner(margin);
+    let mut dest_area = area.inner(margin);
+    // Keep the area within the coordinates space so that the constraints can be satisfied
+    dest_area.width = dest_area.right() - dest_area.left();
+    dest_area.height = dest_area.bottom() - dest_area.top();
     for (i, e) in elements.iter().enumerate() {
         vars.insert(e.x, (i, 0));
         vars.insert(e.y, (i, 1));
@@ -181,7 +283,8 @@ pub fn split(area: &Rect, dir: &Direction, margin: u16, sizes: &[Size]) -> Vec<R
                 constraints.push(match *size {
                     Size::Fixed(v) => elements[i].width | EQ(WEAK) | f64::from(v),
                     Size::Percent(v) => {
-                        elements[i].width | EQ(WEAK) | (f64::from(v * dest_area.width) / 100.0)
+                        elements[i].width | EQ(WEAK)
+                            | (f64::from(v) * f64::from(dest_area.width) / 100.0)
                     }
                     Size::Min(v) => elements[i].width | GE(WEAK) | f64::from(v),
                     Size::Max(v) => elements[i].width | LE(WEAK) | f64::from(v),
@@ -198,7 +301,8 @@ pub fn split(area: &Rect, dir: &Direction, margin: u16, sizes: &[Size]) -> Vec<R
                 constraints.push(match *size {
                     Size::Fixed(v) => elements[i].height | EQ(WEAK) | f64::from(v),
                     Size::Percent(v) => {
-                        elements[i].height | EQ(WEAK) | (f64::from(v * dest_area.height) / 100.0)
+                        elements[i].height | EQ(WEAK)
+                            | (f64::from(v) * f64::from(dest_area.height) / 100.0)
                     }
                     Size::Min(v) => elements[i].height | GE(WEAK) | f64::from(v),
                     Size::Max(v) => elements[i].height | LE(WEAK) | f64::from(v),
@@ -206,7 +310,9 @@ pub fn split(area: &Rect, dir: &Direction, margin: u16, sizes: &[Size]) -> Vec<R
             }
         }
     }
-    solver.add_constraints(&constraints).unwrap();
+    if solver.add_constraints(&constraints).is_err() {
+        return results;
+    }
     for &(var, value) in solver.fetch_changes() {
         let (index, attr) = vars[&var];
         let value = if value.is_sign_negative() {
@@ -235,10 +341,10 @@ pub fn split(area: &Rect, dir: &Direction, margin: u16, sizes: &[Size]) -> Vec<R
     if let Some(last) = results.last_mut() {
         match *dir {
             Direction::Vertical => {
-                last.height = dest_area.bottom() - last.y;
+                last.height = dest_area.bottom().saturating_sub(last.y);
             }
             Direction::Horizontal => {
-                last.width = dest_area.right() - last.x;
+                last.width = dest_area.right().saturating_sub(last.x);
             }
         }
     }
@@ -335,3 +441,107 @@ impl Group {
         f(t, &chunks);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_area_does_not_overflow() {
+        assert_eq!(Rect::new(0, 0, 300, 300).area(), 90_000);
+        assert_eq!(Rect::new(10, 10, 0, 5).area(), 0);
+        assert_eq!(Rect::from((3, 4, 5, 6)).with_width(2).with_height(7).area(), 14);
+    }
+
+    #[test]
+    fn rect_edges() {
+        let rect = Rect::from((3, 4, 5, 6));
+        assert_eq!(rect, Rect::new(3, 4, 5, 6));
+        assert_eq!(rect.left(), 3);
+        assert_eq!(rect.right(), 8);
+        assert_eq!(rect.top(), 4);
+        assert_eq!(rect.bottom(), 10);
+
+        let empty = Rect::new(3, 4, 0, 0);
+        assert_eq!(empty.left(), empty.right());
+        assert_eq!(empty.top(), empty.bottom());
+    }
+
+    /// A few rectangles lying on the edges of the coordinates space
+    fn degenerate_rects() -> Vec<Rect> {
+        let values = [0, 1, 2, 3, u16::MAX - 1, u16::MAX];
+        let mut rects = Vec::new();
+        for &x in &values {
+            for &y in &values {
+                for &width in &values {
+                    for &height in &values {
+                        rects.push(Rect::new(x, y, width, height));
+                    }
+                }
+            }
+        }
+        rects
+    }
+
+    #[test]
+    fn degenerate_rects_do_not_overflow() {
+        let rects = degenerate_rects();
+        for a in &rects {
+            assert!(a.right() >= a.left() && a.bottom() >= a.top());
+            for &margin in &[0, 1, 2, u16::MAX] {
+                let inner = a.inner(margin);
+                assert!(inner.area() <= a.area(), "{:?} {}", a, margin);
+            }
+        }
+        for a in rects.iter().step_by(7) {
+            for b in rects.iter().step_by(11) {
+                let union = a.union(b);
+                let intersection = a.intersection(b);
+                assert!(intersection.area() <= min(a.area(), b.area()), "{:?} {:?}", a, b);
+                for r in &[a, b] {
+                    assert!(union.left() <= r.left() && union.right() >= r.right());
+                    assert!(union.top() <= r.top() && union.bottom() >= r.bottom());
+                }
+                if !a.intersects(b) {
+                    assert_eq!(intersection.area(), 0, "{:?} {:?}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn grids_cover_the_whole_area() {
+        let area = Rect::new(0, 0, 30, 30);
+        let cells = grid(&area, 3, 3, 0);
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[4], Rect::new(10, 10, 10, 10));
+        assert_eq!(cells.iter().map(|c| c.area()).sum::<u32>(), area.area());
+        assert!(cells.iter().fold(Rect::default(), |u, c| u.union(c)) == area);
+
+        // The remainder is given to the first rows and columns
+        let cells = grid(&Rect::new(1, 2, 11, 3), 2, 3, 0);
+        let widths = cells.iter().map(|c| (c.x, c.width)).collect::<Vec<(u16, u16)>>();
+        assert_eq!(widths[..3], [(1, 4), (5, 4), (9, 3)]);
+        let heights = cells.iter().map(|c| (c.y, c.height)).collect::<Vec<(u16, u16)>>();
+        assert_eq!((heights[0], heights[3]), ((2, 2), (4, 1)));
+
+        assert_eq!(grid(&area, 0, 3, 1), vec![]);
+        for cell in grid(&Rect::new(0, 0, 3, 3), 4, 4, 2) {
+            assert!(cell.right() <= 3 && cell.bottom() <= 3, "{:?}", cell);
+        }
+    }
+
+    #[test]
+    fn splitting_degenerate_rects_does_not_panic() {
+        let sizes = [Size::Percent(90), Size::Fixed(3), Size::Min(1), Size::Max(2)];
+        for area in degenerate_rects().iter().step_by(13) {
+            for dir in &[Direction::Horizontal, Direction::Vertical] {
+                for &margin in &[0, 1, u16::MAX] {
+                    for chunk in split(area, dir, margin, &sizes) {
+                        assert!(chunk.right() <= area.right() && chunk.bottom() <= area.bottom());
+                    }
+                }
+            }
+        }
+    }
+}
@@ -168,5 +168,9 @@ pub mod terminal;
 pub mod widgets;
 pub mod style;
 pub mod layout;
+pub mod animation;
+pub mod hitmap;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
-pub use self::terminal::Terminal;
+pub use self::terminal::{render_to_buffer, Terminal};
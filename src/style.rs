@@ -20,6 +20,125 @@ pub enum Color {
     Rgb(u8, u8, u8),
 }
 
+impl Color {
+    /// Returns the escape sequence setting this color as the foreground
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::Red.to_fg_ansi(), "\u{1b}[31m");
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_fg_ansi(), "\u{1b}[38;2;1;2;3m");
+    /// ```
+    pub fn to_fg_ansi(&self) -> String {
+        format!("\u{1b}[{}m", self.fg_code())
+    }
+
+    /// Returns the escape sequence setting this color as the background
+    pub fn to_bg_ansi(&self) -> String {
+        format!("\u{1b}[{}m", self.bg_code())
+    }
+
+    /// Returns the SGR parameters setting this color as the foreground
+    pub(crate) fn fg_code(&self) -> String {
+        match *self {
+            Color::Reset => String::from("39"),
+            Color::Black => String::from("30"),
+            Color::Red => String::from("31"),
+            Color::Green => String::from("32"),
+            Color::Yellow => String::from("33"),
+            Color::Blue => String::from("34"),
+            Color::Magenta => String::from("35"),
+            Color::Cyan => String::from("36"),
+            Color::Gray => String::from("37"),
+            Color::DarkGray => String::from("90"),
+            Color::LightRed => String::from("91"),
+            Color::LightGreen => String::from("92"),
+            Color::LightYellow => String::from("93"),
+            Color::LightBlue => String::from("94"),
+            Color::LightMagenta => String::from("95"),
+            Color::LightCyan => String::from("96"),
+            Color::White => String::from("97"),
+            Color::Rgb(r, g, b) => format!("38;2;{};{};{}", r, g, b),
+        }
+    }
+
+    /// Returns the SGR parameters setting this color as the background
+    pub(crate) fn bg_code(&self) -> String {
+        match *self {
+            Color::Reset => String::from("49"),
+            Color::Black => String::from("40"),
+            Color::Red => String::from("41"),
+            Color::Green => String::from("42"),
+            Color::Yellow => String::from("43"),
+            Color::Blue => String::from("44"),
+            Color::Magenta => String::from("45"),
+            Color::Cyan => String::from("46"),
+            Color::Gray => String::from("47"),
+            Color::DarkGray => String::from("100"),
+            Color::LightRed => String::from("101"),
+            Color::LightGreen => String::from("102"),
+            Color::LightYellow => String::from("103"),
+            Color::LightBlue => String::from("104"),
+            Color::LightMagenta => String::from("105"),
+            Color::LightCyan => String::from("106"),
+            Color::White => String::from("107"),
+            Color::Rgb(r, g, b) => format!("48;2;{};{};{}", r, g, b),
+        }
+    }
+
+    /// Returns the approximate RGB components of this color, the named colors being resolved
+    /// with the default palette of xterm. The default color of the terminal is unknown.
+    fn rgb(&self) -> Option<(u8, u8, u8)> {
+        match *self {
+            Color::Reset => None,
+            Color::Black => Some((0, 0, 0)),
+            Color::Red => Some((205, 0, 0)),
+            Color::Green => Some((0, 205, 0)),
+            Color::Yellow => Some((205, 205, 0)),
+            Color::Blue => Some((0, 0, 238)),
+            Color::Magenta => Some((205, 0, 205)),
+            Color::Cyan => Some((0, 205, 205)),
+            Color::Gray => Some((229, 229, 229)),
+            Color::DarkGray => Some((127, 127, 127)),
+            Color::LightRed => Some((255, 0, 0)),
+            Color::LightGreen => Some((0, 255, 0)),
+            Color::LightYellow => Some((255, 255, 0)),
+            Color::LightBlue => Some((92, 92, 255)),
+            Color::LightMagenta => Some((255, 0, 255)),
+            Color::LightCyan => Some((0, 255, 255)),
+            Color::White => Some((255, 255, 255)),
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+        }
+    }
+
+    /// Returns the color text should be written with to be readable on top of the given
+    /// background: white over dark colors and black over light ones. The default foreground is
+    /// returned over the default background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::readable_over(Color::Rgb(0, 0, 120)), Color::White);
+    /// assert_eq!(Color::readable_over(Color::Yellow), Color::Black);
+    /// ```
+    pub fn readable_over(bg: Color) -> Color {
+        match bg.rgb() {
+            None => Color::Reset,
+            Some((r, g, b)) => {
+                // Perceived brightness, out of 255 000
+                let brightness = u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114;
+                if brightness < 128_000 {
+                    Color::White
+                } else {
+                    Color::Black
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Modifier {
     Blink,
@@ -77,3 +196,47 @@ impl Style {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_to_ansi() {
+        let colors = [
+            (Color::Reset, "39", "49"),
+            (Color::Black, "30", "40"),
+            (Color::Red, "31", "41"),
+            (Color::Green, "32", "42"),
+            (Color::Yellow, "33", "43"),
+            (Color::Blue, "34", "44"),
+            (Color::Magenta, "35", "45"),
+            (Color::Cyan, "36", "46"),
+            (Color::Gray, "37", "47"),
+            (Color::DarkGray, "90", "100"),
+            (Color::LightRed, "91", "101"),
+            (Color::LightGreen, "92", "102"),
+            (Color::LightYellow, "93", "103"),
+            (Color::LightBlue, "94", "104"),
+            (Color::LightMagenta, "95", "105"),
+            (Color::LightCyan, "96", "106"),
+            (Color::White, "97", "107"),
+            (Color::Rgb(12, 0, 255), "38;2;12;0;255", "48;2;12;0;255"),
+        ];
+        for &(color, fg, bg) in &colors {
+            assert_eq!(color.to_fg_ansi(), format!("\u{1b}[{}m", fg));
+            assert_eq!(color.to_bg_ansi(), format!("\u{1b}[{}m", bg));
+        }
+    }
+
+    #[test]
+    fn text_is_black_over_light_colors_and_white_over_dark_ones() {
+        assert_eq!(Color::readable_over(Color::Blue), Color::White);
+        assert_eq!(Color::readable_over(Color::Rgb(0, 0, 100)), Color::White);
+        assert_eq!(Color::readable_over(Color::Yellow), Color::Black);
+        assert_eq!(Color::readable_over(Color::LightYellow), Color::Black);
+        assert_eq!(Color::readable_over(Color::Black), Color::White);
+        assert_eq!(Color::readable_over(Color::White), Color::Black);
+        assert_eq!(Color::readable_over(Color::Reset), Color::Reset);
+    }
+}
@@ -20,6 +20,26 @@ pub mod bar {
     pub const ONE_EIGHTH: &str = "▁";
 }
 
+pub mod scrollbar {
+    pub const TRACK: &str = "░";
+    pub const THUMB: &str = "█";
+    pub const UP: &str = "▲";
+    pub const DOWN: &str = "▼";
+    pub const LEFT: &str = "◀";
+    pub const RIGHT: &str = "▶";
+}
+
+pub mod bubble {
+    /// Markers of bubbles from the smallest to the largest
+    pub const SIZES: [&str; 4] = ["·", "•", "●", "█"];
+}
+
+pub mod pattern {
+    pub const DOTS: &str = "·";
+    pub const HATCH: &str = "╱";
+    pub const CROSS: &str = "╳";
+}
+
 pub mod line {
     pub const TOP_RIGHT: &str = "┐";
     pub const VERTICAL: &str = "│";
@@ -31,6 +51,84 @@ pub mod line {
     pub const VERTICAL_RIGHT: &str = "├";
     pub const HORIZONTAL_DOWN: &str = "┬";
     pub const HORIZONTAL_UP: &str = "┴";
+
+    pub const ROUNDED_TOP_RIGHT: &str = "╮";
+    pub const ROUNDED_TOP_LEFT: &str = "╭";
+    pub const ROUNDED_BOTTOM_RIGHT: &str = "╯";
+    pub const ROUNDED_BOTTOM_LEFT: &str = "╰";
+
+    pub const DOUBLE_VERTICAL: &str = "║";
+    pub const DOUBLE_HORIZONTAL: &str = "═";
+    pub const DOUBLE_TOP_RIGHT: &str = "╗";
+    pub const DOUBLE_TOP_LEFT: &str = "╔";
+    pub const DOUBLE_BOTTOM_RIGHT: &str = "╝";
+    pub const DOUBLE_BOTTOM_LEFT: &str = "╚";
+    pub const DOUBLE_VERTICAL_LEFT: &str = "╣";
+    pub const DOUBLE_VERTICAL_RIGHT: &str = "╠";
+
+    pub const THICK_VERTICAL: &str = "┃";
+    pub const THICK_HORIZONTAL: &str = "━";
+    pub const THICK_TOP_RIGHT: &str = "┓";
+    pub const THICK_TOP_LEFT: &str = "┏";
+    pub const THICK_BOTTOM_RIGHT: &str = "┛";
+    pub const THICK_BOTTOM_LEFT: &str = "┗";
+    pub const THICK_VERTICAL_LEFT: &str = "┫";
+    pub const THICK_VERTICAL_RIGHT: &str = "┣";
+
+    /// The symbols needed to draw a box
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Set {
+        pub vertical: &'static str,
+        pub horizontal: &'static str,
+        pub top_right: &'static str,
+        pub top_left: &'static str,
+        pub bottom_right: &'static str,
+        pub bottom_left: &'static str,
+        pub vertical_left: &'static str,
+        pub vertical_right: &'static str,
+    }
+
+    pub const NORMAL: Set = Set {
+        vertical: VERTICAL,
+        horizontal: HORIZONTAL,
+        top_right: TOP_RIGHT,
+        top_left: TOP_LEFT,
+        bottom_right: BOTTOM_RIGHT,
+        bottom_left: BOTTOM_LEFT,
+        vertical_left: VERTICAL_LEFT,
+        vertical_right: VERTICAL_RIGHT,
+    };
+
+    pub const ROUNDED: Set = Set {
+        top_right: ROUNDED_TOP_RIGHT,
+        top_left: ROUNDED_TOP_LEFT,
+        bottom_right: ROUNDED_BOTTOM_RIGHT,
+        bottom_left: ROUNDED_BOTTOM_LEFT,
+        ..NORMAL
+    };
+
+    pub const DOUBLE: Set = Set {
+        vertical: DOUBLE_VERTICAL,
+        horizontal: DOUBLE_HORIZONTAL,
+        top_right: DOUBLE_TOP_RIGHT,
+        top_left: DOUBLE_TOP_LEFT,
+        bottom_right: DOUBLE_BOTTOM_RIGHT,
+        bottom_left: DOUBLE_BOTTOM_LEFT,
+        vertical_left: DOUBLE_VERTICAL_LEFT,
+        vertical_right: DOUBLE_VERTICAL_RIGHT,
+    };
+
+    pub const THICK: Set = Set {
+        vertical: THICK_VERTICAL,
+        horizontal: THICK_HORIZONTAL,
+        top_right: THICK_TOP_RIGHT,
+        top_left: THICK_TOP_LEFT,
+        bottom_right: THICK_BOTTOM_RIGHT,
+        bottom_left: THICK_BOTTOM_LEFT,
+        vertical_left: THICK_VERTICAL_LEFT,
+        vertical_right: THICK_VERTICAL_RIGHT,
+    };
 }
 
 pub const DOT: &str = "•";
+pub const ELLIPSIS: &str = "…";
@@ -1,11 +1,39 @@
 use std::io;
+use std::mem;
+use std::ops::ControlFlow;
 use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use backend::Backend;
+use backend::{Backend, TestBackend};
 use buffer::Buffer;
 use layout::{split, Group, Rect};
+use style::{Modifier, Style};
 use widgets::Widget;
 
+/// Returns the spans of cells covered by the dirty areas lying in the given area, as a row and a
+/// range of columns. Overlapping spans are merged so that each cell is covered once, and the
+/// spans are sorted in the order of the cells of a buffer.
+fn dirty_spans(dirty: &[Rect], area: &Rect) -> Vec<(u16, u16, u16)> {
+    let mut spans = dirty
+        .iter()
+        .map(|r| area.intersection(r))
+        .flat_map(|r| (r.top()..r.bottom()).map(move |y| (y, r.left(), r.right())))
+        .collect::<Vec<(u16, u16, u16)>>();
+    spans.sort();
+    let mut merged: Vec<(u16, u16, u16)> = Vec::with_capacity(spans.len());
+    for (y, start, end) in spans {
+        match merged.last_mut() {
+            Some(&mut (row, _, ref mut last)) if row == y && start <= *last => {
+                *last = (*last).max(end);
+            }
+            _ => merged.push((y, start, end)),
+        }
+    }
+    merged
+}
+
 /// Holds a computed layout and keeps track of its use between successive draw calls
 #[derive(Debug)]
 pub struct LayoutEntry {
@@ -26,6 +54,13 @@ where
     buffers: [Buffer; 2],
     /// Index of the current buffer in the previous array
     current: usize,
+    /// Areas to update during the next draw call (the whole screen is updated if empty)
+    dirty: Vec<Rect>,
+    /// Whether each cell of the previous frame was a ghost when ghost trails are enabled
+    ghosts: Option<Vec<bool>>,
+    /// Whether the current buffer holds a frame which has not been drawn yet, i.e. something has
+    /// been rendered since the last draw call or nothing has been drawn at all
+    pending: bool,
 }
 
 impl<B> Terminal<B>
@@ -41,6 +76,9 @@ where
             layout_cache: HashMap::new(),
             buffers: [Buffer::empty(size), Buffer::empty(size)],
             current: 0,
+            dirty: Vec::new(),
+            ghosts: None,
+            pending: true,
         })
     }
 
@@ -73,33 +111,111 @@ where
         entry.chunks.clone()
     }
 
+    /// Returns the buffer the next frame is rendered to. At the end of each draw call, it is
+    /// compared to the buffer of the previous frame and only the cells that changed are sent to
+    /// the backend, after what both buffers are swapped and the new current one is cleared.
+    ///
+    /// This gives direct access to the frame to those rendering to it without going through
+    /// widgets.
+    pub fn current_buffer_mut(&mut self) -> &mut Buffer {
+        self.pending = true;
+        &mut self.buffers[self.current]
+    }
+
+    /// Marks an area of the screen as dirty. As soon as one area has been marked, only the cells
+    /// inside dirty areas are compared and sent to the backend during the next draw call. The
+    /// rest of the screen is assumed to be unchanged and keeps its previous content.
+    pub fn mark_dirty(&mut self, area: Rect) {
+        self.dirty.push(area);
+    }
+
+    /// Set the symbol and the style of the cells each frame starts with, which are output
+    /// wherever nothing is drawn (e.g. `░` to draw the interface onto a textured background).
+    /// Frames are cleared with blank spaces of the default style otherwise.
+    pub fn blank(&mut self, symbol: &str, style: Style) {
+        for buffer in &mut self.buffers {
+            buffer.set_blank(symbol, style);
+        }
+        self.buffers[self.current].reset();
+    }
+
+    /// Keeps the content of the previous frame faintly visible for one more frame wherever the
+    /// new frame leaves the cells blank, leaving trails behind moving points (e.g. on live
+    /// charts).
+    pub fn ghost_trails(&mut self, enabled: bool) {
+        self.ghosts = if enabled {
+            Some(vec![false; self.buffers[self.current].content.len()])
+        } else {
+            None
+        };
+    }
+
+    /// Fills the blank cells of the current frame with the faint content of the previous one,
+    /// except the cells which were already ghosts
+    fn draw_ghosts(&mut self) {
+        let ghosts = match self.ghosts {
+            Some(ref mut ghosts) => ghosts,
+            None => return,
+        };
+        let (current, previous) = split_buffers(&mut self.buffers, self.current);
+        let blank = current.blank().clone();
+        ghosts.resize(current.content.len(), false);
+        for (i, ghost) in ghosts.iter_mut().enumerate() {
+            let was_ghost = mem::replace(ghost, false);
+            if current.content[i] == blank && previous.content[i] != blank && !was_ghost {
+                current.content[i] = previous.content[i].clone();
+                current.content[i].set_modifier(Modifier::Faint);
+                *ghost = true;
+            }
+        }
+    }
+
     /// Builds a string representing the minimal escape sequences and characters set necessary to
     /// update the UI and writes it to stdout.
     pub fn flush(&mut self) -> Result<(), io::Error> {
-        let width = self.buffers[self.current].area.width;
-        let content = self.buffers[self.current]
-            .content
-            .iter()
-            .zip(self.buffers[1 - self.current].content.iter())
-            .enumerate()
-            .filter_map(|(i, (c, p))| {
-                if c != p {
-                    let i = i as u16;
-                    let x = i % width;
-                    let y = i / width;
-                    Some((x, y, c))
-                } else {
-                    None
-                }
-            });
-        self.backend.draw(content)
+        let current = &self.buffers[self.current];
+        let previous = &self.buffers[1 - self.current];
+        if self.dirty.is_empty() {
+            let width = current.area.width;
+            let content = current
+                .content
+                .iter()
+                .zip(previous.content.iter())
+                .enumerate()
+                .filter_map(|(i, (c, p))| {
+                    let x = (i % width as usize) as u16;
+                    let y = (i / width as usize) as u16;
+                    if c != p {
+                        Some((x, y, c))
+                    } else {
+                        None
+                    }
+                });
+            self.backend.draw(content)
+        } else {
+            // Only the cells of the dirty areas are compared
+            let spans = dirty_spans(&self.dirty, &current.area);
+            let content = spans
+                .iter()
+                .flat_map(|&(y, start, end)| (start..end).map(move |x| (x, y)))
+                .filter_map(|(x, y)| {
+                    let i = current.index_of(x, y);
+                    if current.content[i] != previous.content[i] {
+                        Some((x, y, &current.content[i]))
+                    } else {
+                        None
+                    }
+                });
+            self.backend.draw(content)
+        }
     }
 
     /// Calls the draw method of a given widget on the current buffer
     pub fn render<W>(&mut self, widget: &mut W, area: &Rect)
     where
-        W: Widget,
+        W: Widget + ?Sized,
     {
+        self.pending = true;
         widget.draw(area, &mut self.buffers[self.current]);
     }
 
@@ -107,14 +223,22 @@ where
     /// This leads to a full redraw of the screen.
     pub fn resize(&mut self, area: Rect) -> Result<(), io::Error> {
         self.buffers[self.current].resize(area);
-        self.buffers[1 - self.current].resize(area);
-        self.buffers[1 - self.current].reset();
+        // The screen is cleared with default blank spaces whatever the blank of the frames
+        let previous = &mut self.buffers[1 - self.current];
+        previous.resize(area);
+        previous.clear_with(&area, " ", &Style::default());
         self.layout_cache.clear();
+        self.dirty.clear();
+        if let Some(ref mut ghosts) = self.ghosts {
+            ghosts.clear();
+        }
         self.backend.clear()
     }
 
     /// Flushes the current internal state and prepares the interface for the next draw call
     pub fn draw(&mut self) -> Result<(), io::Error> {
+        self.draw_ghosts();
+
         // Draw to stdout
         self.flush()?;
 
@@ -132,21 +256,112 @@ where
             e.hot = false;
         }
 
-        // Swap buffers
-        self.buffers[1 - self.current].reset();
-        self.current = 1 - self.current;
+        if self.dirty.is_empty() {
+            // Swap buffers
+            self.current = 1 - self.current;
+        } else {
+            // The content of the screen outside of the dirty areas is unchanged: the previous
+            // buffer is updated in place with the dirty areas only and keeps holding the screen
+            let (current, previous) = split_buffers(&mut self.buffers, self.current);
+            for (y, start, end) in dirty_spans(&self.dirty, &current.area) {
+                let first = current.index_of(start, y);
+                let last = first + usize::from(end - start);
+                previous.content[first..last].clone_from_slice(&current.content[first..last]);
+            }
+            self.dirty.clear();
+        }
+        self.buffers[self.current].reset();
+        self.pending = false;
 
         // Flush
         self.backend.flush()?;
         Ok(())
     }
 
+    /// Runs the main loop of an interface redrawn at a fixed rate, typically a live dashboard.
+    ///
+    /// The closure is called once right away and then at each tick with the events received on
+    /// the channel since the previous call (e.g. the keys read by an input thread). It renders
+    /// the next frame, which is drawn as soon as it returns, unless it returns
+    /// `ControlFlow::Break` to end the loop. The ticks keep going once the channel is
+    /// disconnected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # extern crate tui;
+    /// # use std::ops::ControlFlow;
+    /// # use std::sync::mpsc;
+    /// # use std::time::Duration;
+    /// # use tui::Terminal;
+    /// # use tui::backend::{Key, RawBackend};
+    /// # use tui::widgets::{Gauge, Widget};
+    /// # fn main() {
+    /// let mut terminal = Terminal::new(RawBackend::new().unwrap()).unwrap();
+    /// let (tx, rx) = mpsc::channel();
+    /// # tx.send(Key::Char('q')).unwrap();
+    /// let mut progress = 0;
+    /// terminal
+    ///     .run(Duration::from_millis(250), &rx, |t, keys| {
+    ///         if keys.contains(&Key::Char('q')) {
+    ///             return ControlFlow::Break(());
+    ///         }
+    ///         progress = (progress + 1) % 100;
+    ///         let size = t.size().unwrap();
+    ///         Gauge::default().percent(progress).render(t, &size);
+    ///         ControlFlow::Continue(())
+    ///     })
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn run<E, F>(
+        &mut self,
+        tick_rate: Duration,
+        events: &Receiver<E>,
+        mut f: F,
+    ) -> Result<(), io::Error>
+    where
+        F: FnMut(&mut Terminal<B>, &[E]) -> ControlFlow<()>,
+    {
+        let mut received = Vec::new();
+        loop {
+            let deadline = Instant::now() + tick_rate;
+            if f(self, &received).is_break() {
+                return Ok(());
+            }
+            self.draw()?;
+            received.clear();
+            // The events sent while the frame was rendered are dispatched with the next one even
+            // when rendering it took longer than a tick
+            received.extend(events.try_iter());
+            loop {
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                match events.recv_timeout(deadline - now) {
+                    Ok(event) => received.push(event),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(deadline - now);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn hide_cursor(&mut self) -> Result<(), io::Error> {
         self.backend.hide_cursor()
     }
     pub fn show_cursor(&mut self) -> Result<(), io::Error> {
         self.backend.show_cursor()
     }
+    /// Moves the cursor to the given cell. Drawing moves the cursor, so this has to be called
+    /// after each draw call for the cursor to stay in place.
+    pub fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), io::Error> {
+        self.backend.set_cursor(x, y)
+    }
     pub fn clear(&mut self) -> Result<(), io::Error> {
         self.backend.clear()
     }
@@ -154,3 +369,268 @@ where
         self.backend.size()
     }
 }
+
+/// Returns the current buffer and the previous one, given the index of the current one
+fn split_buffers(buffers: &mut [Buffer; 2], current: usize) -> (&mut Buffer, &mut Buffer) {
+    let (first, second) = buffers.split_at_mut(1);
+    if current == 0 {
+        (&mut first[0], &mut second[0])
+    } else {
+        (&mut second[0], &mut first[0])
+    }
+}
+
+/// Renders widgets to a standalone buffer of the given size instead of an actual terminal. The
+/// closure receives a terminal that can be used exactly as a regular one to compute layouts and
+/// render widgets. What has been rendered is returned once the closure is done: the frame being
+/// rendered, or the last frame drawn if the closure draws and renders nothing afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::render_to_buffer;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Block, Borders, Widget};
+/// # fn main() {
+/// let buf = render_to_buffer(Rect::new(0, 0, 4, 3), |t| {
+///     let size = t.size().unwrap();
+///     Block::default().borders(Borders::ALL).render(t, &size);
+/// });
+/// assert_eq!(buf.to_string(), "┌──┐\n│  │\n└──┘");
+/// # }
+/// ```
+pub fn render_to_buffer<F>(area: Rect, f: F) -> Buffer
+where
+    F: FnOnce(&mut Terminal<TestBackend>),
+{
+    let mut terminal = Terminal {
+        backend: TestBackend::new(area),
+        layout_cache: HashMap::new(),
+        buffers: [Buffer::empty(area), Buffer::empty(area)],
+        current: 0,
+        dirty: Vec::new(),
+        ghosts: None,
+        pending: true,
+    };
+    f(&mut terminal);
+    let rendered = if terminal.pending {
+        terminal.current
+    } else {
+        1 - terminal.current
+    };
+    mem::take(&mut terminal.buffers[rendered])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A widget filling its area with the given symbol
+    struct Fill(&'static str);
+
+    impl Widget for Fill {
+        fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    buf.get_mut(x, y).set_symbol(self.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn only_dirty_areas_are_drawn() {
+        let size = Rect::new(0, 0, 10, 5);
+        let backend = TestBackend::new(size);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.render(&mut Fill("a"), &size);
+        terminal.draw().unwrap();
+        assert_eq!(terminal.backend().drawn().len(), 50);
+
+        terminal.backend_mut().clear_drawn();
+        let dirty = Rect::new(2, 1, 3, 2);
+        terminal.mark_dirty(dirty);
+        terminal.render(&mut Fill("b"), &size);
+        terminal.draw().unwrap();
+        let drawn = terminal.backend().drawn().to_vec();
+        assert_eq!(drawn.len(), 6);
+        assert!(drawn.iter().all(|&(x, y)| {
+            x >= dirty.left() && x < dirty.right() && y >= dirty.top() && y < dirty.bottom()
+        }));
+
+        // The cells outside the dirty area are still known to hold the first content
+        terminal.backend_mut().clear_drawn();
+        terminal.render(&mut Fill("a"), &size);
+        terminal.draw().unwrap();
+        assert_eq!(terminal.backend().drawn().len(), 6);
+    }
+
+    #[test]
+    fn overlapping_dirty_areas_are_drawn_once() {
+        let dirty = [Rect::new(1, 0, 3, 2), Rect::new(2, 1, 4, 2), Rect::new(8, 0, 5, 5)];
+        assert_eq!(
+            dirty_spans(&dirty, &Rect::new(0, 0, 10, 3)),
+            vec![(0, 1, 4), (0, 8, 10), (1, 1, 6), (1, 8, 10), (2, 2, 6), (2, 8, 10)]
+        );
+
+        let size = Rect::new(0, 0, 10, 3);
+        let backend = TestBackend::new(size);
+        let mut terminal = Terminal::new(backend).unwrap();
+        for area in &dirty {
+            terminal.mark_dirty(*area);
+        }
+        terminal.render(&mut Fill("a"), &size);
+        terminal.draw().unwrap();
+        assert_eq!(terminal.backend().drawn().len(), 3 + 2 + 5 + 2 + 4 + 2);
+    }
+
+    #[test]
+    fn identical_frames_are_only_drawn_once() {
+        let size = Rect::new(0, 0, 4, 2);
+        let backend = TestBackend::new(size);
+        let mut terminal = Terminal::new(backend).unwrap();
+        for _ in 0..2 {
+            terminal
+                .current_buffer_mut()
+                .set_string(0, 0, "ab", &Default::default());
+            terminal.draw().unwrap();
+        }
+        assert_eq!(terminal.backend().drawn(), &[(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn frames_are_cleared_with_the_blank_cell_across_resizes() {
+        let backend = TestBackend::new(Rect::new(0, 0, 2, 1));
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.blank("░", Style::default());
+        assert_eq!(terminal.current_buffer_mut().to_string(), "░░");
+        terminal.draw().unwrap();
+        assert_eq!(terminal.backend().drawn().len(), 2);
+        assert_eq!(terminal.current_buffer_mut().to_string(), "░░");
+
+        terminal.backend_mut().clear_drawn();
+        terminal.resize(Rect::new(0, 0, 3, 2)).unwrap();
+        assert_eq!(terminal.current_buffer_mut().to_string(), "░░░\n░░░");
+        terminal.draw().unwrap();
+        // The screen was cleared by the resize so the whole blank frame is drawn again
+        assert_eq!(terminal.backend().drawn().len(), 6);
+        assert_eq!(terminal.current_buffer_mut().to_string(), "░░░\n░░░");
+    }
+
+    #[test]
+    fn previous_frames_leave_faint_trails() {
+        let size = Rect::new(0, 0, 3, 1);
+        let backend = TestBackend::new(size);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.ghost_trails(true);
+        let frames = ["a  ", " b ", "   "];
+        let mut displayed = Vec::new();
+        for frame in &frames {
+            terminal
+                .current_buffer_mut()
+                .set_string(0, 0, frame, &Default::default());
+            terminal.draw().unwrap();
+            let previous = &terminal.buffers[1 - terminal.current];
+            displayed.push((
+                previous.to_string(),
+                previous.get(0, 0).style.modifier,
+                previous.get(1, 0).style.modifier,
+            ));
+        }
+        // Ghosts do not leave ghosts themselves
+        assert_eq!(
+            displayed,
+            vec![
+                ("a  ".to_owned(), Modifier::Reset, Modifier::Reset),
+                ("ab ".to_owned(), Modifier::Faint, Modifier::Reset),
+                (" b ".to_owned(), Modifier::Reset, Modifier::Faint),
+            ]
+        );
+    }
+
+    #[test]
+    fn the_loop_draws_a_frame_per_tick_until_it_is_stopped() {
+        use backend::Key;
+        use std::sync::mpsc;
+
+        let backend = TestBackend::new(Rect::new(0, 0, 3, 1));
+        let mut terminal = Terminal::new(backend).unwrap();
+        let (tx, rx) = mpsc::channel();
+        let mut received = Vec::new();
+        let mut tick = 0;
+        terminal
+            .run(Duration::from_millis(1), &rx, |t, keys| {
+                received.push(keys.to_vec());
+                // The loop is stopped anyway if the events are not dispatched as expected
+                if keys.contains(&Key::Esc) || received.len() > 8 {
+                    return ControlFlow::Break(());
+                }
+                t.current_buffer_mut()
+                    .set_string(tick, 0, "x", &Default::default());
+                tick += 1;
+                // Frames slower than the ticks
+                thread::sleep(Duration::from_millis(2));
+                // Synthetic input, dispatched at the next tick however long this one takes
+                tx.send(if tick == 3 { Key::Esc } else { Key::Char('a') })
+                    .unwrap();
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+
+        let a = Key::Char('a');
+        assert_eq!(received, vec![vec![], vec![a], vec![a], vec![Key::Esc]]);
+        // The frame rendered when the loop is stopped is not drawn
+        assert_eq!(terminal.backend().drawn(), &[(0, 0), (0, 0), (1, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn the_last_drawn_frame_is_kept_when_rendering_offscreen() {
+        let buf = render_to_buffer(Rect::new(0, 0, 3, 1), |t| {
+            t.render(&mut Fill("a"), &Rect::new(0, 0, 2, 1));
+            t.draw().unwrap();
+        });
+        assert_eq!(buf.to_string(), "aa ");
+
+        // What is rendered after drawing the last frame makes a new frame
+        let buf = render_to_buffer(Rect::new(0, 0, 3, 1), |t| {
+            t.render(&mut Fill("a"), &Rect::new(0, 0, 2, 1));
+            t.draw().unwrap();
+            t.render(&mut Fill("b"), &Rect::new(2, 0, 1, 1));
+        });
+        assert_eq!(buf.to_string(), "  b");
+    }
+
+    #[test]
+    fn widgets_can_be_rendered_offscreen() {
+        use layout::{Direction, Group, Size};
+        use symbols;
+        use widgets::{Axis, Block, Borders, Chart, Dataset};
+
+        let data = [(0.0, 0.0), (10.0, 10.0)];
+        let datasets = [Dataset::default().data(&data)];
+        let buf = render_to_buffer(Rect::new(0, 0, 20, 10), |t| {
+            let size = t.size().unwrap();
+            Group::default()
+                .direction(Direction::Vertical)
+                .sizes(&[Size::Fixed(3), Size::Min(0)])
+                .render(t, &size, |t, chunks| {
+                    Block::default()
+                        .title("Title")
+                        .borders(Borders::ALL)
+                        .render(t, &chunks[0]);
+                    Chart::<&str, &str>::default()
+                        .x_axis(Axis::default().bounds([0.0, 10.0]))
+                        .y_axis(Axis::default().bounds([0.0, 10.0]))
+                        .datasets(&datasets)
+                        .render(t, &chunks[1]);
+                });
+        });
+
+        assert_eq!(buf.area, Rect::new(0, 0, 20, 10));
+        assert_eq!(buf.get(1, 0).symbol, "T");
+        assert_eq!(buf.get(19, 2).symbol, symbols::line::BOTTOM_RIGHT);
+        assert_eq!(buf.get(0, 9).symbol, symbols::DOT);
+        assert_eq!(buf.get(19, 3).symbol, symbols::DOT);
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers to test widgets, available with the `testing` feature.
+
+use buffer::Buffer;
+
+/// Checks that the symbols of the given buffer match the expected rows, panicking with both
+/// grids side by side otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::buffer::Buffer;
+/// # use tui::layout::Rect;
+/// # use tui::testing::assert_buffer_eq;
+/// # use tui::widgets::{Block, Borders, Widget};
+/// # fn main() {
+/// let area = Rect::new(0, 0, 4, 3);
+/// let mut buf = Buffer::empty(area);
+/// Block::default().borders(Borders::ALL).draw(&area, &mut buf);
+/// assert_buffer_eq(&buf, &["┌──┐", "│  │", "└──┘"]);
+/// # }
+/// ```
+pub fn assert_buffer_eq(actual: &Buffer, expected: &[&str]) {
+    let rendered = actual.to_string();
+    let actual_rows = rendered.split('\n').collect::<Vec<&str>>();
+    let actual_rows = if actual.area().area() == 0 {
+        Vec::new()
+    } else {
+        actual_rows
+    };
+    if actual_rows == expected {
+        return;
+    }
+
+    let width = expected.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+    let mut message = format!(
+        "buffers differ ({} rows expected, {} rows rendered)\n{:<w$}   actual\n",
+        expected.len(),
+        actual_rows.len(),
+        "expected",
+        w = width + 2
+    );
+    for i in 0..expected.len().max(actual_rows.len()) {
+        let e = expected.get(i).map(|r| format!("\"{}\"", r)).unwrap_or_default();
+        let a = actual_rows
+            .get(i)
+            .map(|r| format!("\"{}\"", r))
+            .unwrap_or_default();
+        let marker = if expected.get(i) == actual_rows.get(i) {
+            ""
+        } else {
+            " <"
+        };
+        let padding = (width + 2).saturating_sub(e.chars().count());
+        message.push_str(&format!("{}{}   {}{}\n", e, " ".repeat(padding), a, marker));
+    }
+    panic!("{}", message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use layout::Rect;
+    use widgets::{Gauge, Widget};
+
+    fn gauge(percent: u16) -> Buffer {
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buf = Buffer::empty(area);
+        Gauge::default().percent(percent).draw(&area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn matching_buffers_are_equal() {
+        assert_buffer_eq(&gauge(50), &["      ", " 50%  "]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\" 50%  \"   \" 42%  \" <")]
+    fn differing_rows_are_reported() {
+        assert_buffer_eq(&gauge(42), &["      ", " 50%  "]);
+    }
+}
@@ -2,10 +2,10 @@ use std::cmp::{max, min};
 
 use unicode_width::UnicodeWidthStr;
 
-use widgets::{Block, Widget};
+use widgets::{Block, Borders, Widget};
 use buffer::Buffer;
 use layout::Rect;
-use style::Style;
+use style::{Color, Style};
 use symbols::bar;
 
 /// Display multiple bars in a single widgets
@@ -35,8 +35,10 @@ pub struct BarChart<'a> {
     bar_width: u16,
     /// The gap between each bar
     bar_gap: u16,
-    /// Style of the values printed at the bottom of each bar
+    /// Style of the values printed on each bar
     value_style: Style,
+    /// Print the values at the top of the bars instead of at their bottom
+    value_on_top: bool,
     /// Style of the labels printed under each bar
     label_style: Style,
     /// Style for the widget
@@ -48,6 +50,16 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// Values to display on the bar (computed when the data is passed to the widget)
     values: Vec<String>,
+    /// Slice of (label, values) pair to plot as groups of bars, one bar for each series
+    groups: &'a [(&'a str, &'a [u64])],
+    /// Names of the series displayed in the legend when plotting groups
+    series: &'a [&'a str],
+    /// Colors given to each series when plotting groups
+    palette: &'a [Color],
+    /// Slice of (label, value) pair to plot as bars going up or down from a zero line
+    signed_data: &'a [(&'a str, i64)],
+    /// Style of the bars of negative values (the widget style if not specified)
+    negative_style: Option<Style>,
 }
 
 impl<'a> Default for BarChart<'a> {
@@ -57,15 +69,29 @@ impl<'a> Default for BarChart<'a> {
             max: None,
             data: &[],
             values: Vec::new(),
+            groups: &[],
+            series: &[],
+            palette: &[],
+            signed_data: &[],
+            negative_style: None,
             bar_width: 1,
             bar_gap: 1,
             value_style: Default::default(),
+            value_on_top: false,
             label_style: Default::default(),
             style: Default::default(),
         }
     }
 }
 
+/// A bar positioned in the chart area
+struct Bar {
+    x: u16,
+    value: u64,
+    value_label: String,
+    style: Style,
+}
+
 impl<'a> BarChart<'a> {
     pub fn data(&'a mut self, data: &'a [(&'a str, u64)]) -> &mut BarChart<'a> {
         self.data = data;
@@ -76,6 +102,40 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Plot groups of bars instead of single bars. The bars of a group are drawn next to each
+    /// other, each group being separated from the next one by the bar gap.
+    pub fn groups(&'a mut self, groups: &'a [(&'a str, &'a [u64])]) -> &mut BarChart<'a> {
+        self.groups = groups;
+        self
+    }
+
+    /// Names of the series displayed in the legend of grouped bars
+    pub fn series(&'a mut self, series: &'a [&'a str]) -> &mut BarChart<'a> {
+        self.series = series;
+        self
+    }
+
+    /// Colors used to draw the bars of each series, wrapping around if there are more series than
+    /// colors
+    pub fn palette(&'a mut self, palette: &'a [Color]) -> &mut BarChart<'a> {
+        self.palette = palette;
+        self
+    }
+
+    /// Plot values which may be negative. The zero line is placed so that the height of the
+    /// chart is shared between the positive and the negative values in proportion to the
+    /// largest of each, positive bars going up from this line and negative bars going down. The
+    /// bars of negative values have a resolution of one cell and the maximum is not used.
+    pub fn signed_data(&'a mut self, data: &'a [(&'a str, i64)]) -> &mut BarChart<'a> {
+        self.signed_data = data;
+        self
+    }
+
+    pub fn negative_style(&'a mut self, style: Style) -> &mut BarChart<'a> {
+        self.negative_style = Some(style);
+        self
+    }
+
     pub fn block(&'a mut self, block: Block<'a>) -> &mut BarChart<'a> {
         self.block = Some(block);
         self
@@ -97,6 +157,10 @@ impl<'a> BarChart<'a> {
         self.value_style = style;
         self
     }
+    pub fn value_on_top(&'a mut self, flag: bool) -> &mut BarChart<'a> {
+        self.value_on_top = flag;
+        self
+    }
     pub fn label_style(&'a mut self, style: Style) -> &mut BarChart<'a> {
         self.label_style = style;
         self
@@ -107,6 +171,132 @@ impl<'a> BarChart<'a> {
     }
 }
 
+/// Returns the symbol of a bar cell filled up to the given number of eighths
+fn bar_symbol(eighths: u64) -> &'static str {
+    match eighths {
+        0 => " ",
+        1 => bar::ONE_EIGHTH,
+        2 => bar::ONE_QUATER,
+        3 => bar::THREE_EIGHTHS,
+        4 => bar::HALF,
+        5 => bar::FIVE_EIGHTHS,
+        6 => bar::THREE_QUATERS,
+        7 => bar::SEVEN_EIGHTHS,
+        _ => bar::FULL,
+    }
+}
+
+impl<'a> BarChart<'a> {
+    /// Returns the style used to draw the bars of the given series
+    fn series_style(&self, series: usize) -> Style {
+        if self.palette.is_empty() {
+            self.style
+        } else {
+            self.style.fg(self.palette[series % self.palette.len()])
+        }
+    }
+
+    /// Computes the bars and the labels (with their position and width) fitting in the given area
+    fn bars(&self, area: &Rect) -> (Vec<Bar>, Vec<(u16, u16, &'a str)>) {
+        let mut bars = Vec::new();
+        let mut labels = Vec::new();
+        if self.groups.is_empty() {
+            let max_index = min(
+                (area.width / (self.bar_width + self.bar_gap)) as usize,
+                self.data.len(),
+            );
+            for (i, &(label, value)) in self.data.iter().take(max_index).enumerate() {
+                let x = area.left() + i as u16 * (self.bar_width + self.bar_gap);
+                bars.push(Bar {
+                    x,
+                    value,
+                    value_label: self.values[i].clone(),
+                    style: self.style,
+                });
+                labels.push((x, self.bar_width, label));
+            }
+        } else {
+            let mut x = area.left();
+            for &(label, values) in self.groups {
+                let width = values.len() as u16 * self.bar_width;
+                if x + width > area.right() {
+                    break;
+                }
+                for (i, &value) in values.iter().enumerate() {
+                    bars.push(Bar {
+                        x: x + i as u16 * self.bar_width,
+                        value,
+                        value_label: format!("{}", value),
+                        style: self.series_style(i),
+                    });
+                }
+                labels.push((x, width, label));
+                x += width + self.bar_gap;
+            }
+        }
+        (bars, labels)
+    }
+
+    /// Draws the signed data in the given area
+    fn draw_signed(&self, area: &Rect, buf: &mut Buffer) {
+        // The last row is reserved for the labels
+        let rows = u64::from(area.height - 1);
+        let positive_max = self.signed_data.iter().fold(0, |acc, &(_, v)| max(acc, v)) as u64;
+        let negative_max = self.signed_data
+            .iter()
+            .fold(0, |acc, &(_, v)| min(acc, v))
+            .unsigned_abs();
+        let total = max(positive_max + negative_max, 1);
+        let positive_rows = (rows * positive_max + total / 2) / total;
+        let zero = area.top() + positive_rows as u16;
+        let max_index = min(
+            (area.width / (self.bar_width + self.bar_gap)) as usize,
+            self.signed_data.len(),
+        );
+        for (i, &(label, value)) in self.signed_data.iter().take(max_index).enumerate() {
+            let x = area.left() + i as u16 * (self.bar_width + self.bar_gap);
+            let value_label = format!("{}", value);
+            let label_width = min(value_label.width() as u16, self.bar_width);
+            let label_x = x + (self.bar_width - label_width) / 2;
+            if value >= 0 {
+                let mut eighths = min(value as u64 * rows * 8 / total, positive_rows * 8);
+                for y in (area.top()..zero).rev() {
+                    for x in x..x + self.bar_width {
+                        buf.get_mut(x, y)
+                            .set_symbol(bar_symbol(eighths))
+                            .set_style(self.style);
+                    }
+                    eighths = eighths.saturating_sub(8);
+                }
+                if value > 0 && zero > area.top() && label_width < self.bar_width {
+                    buf.set_string(label_x, zero - 1, &value_label, &self.value_style);
+                }
+            } else {
+                let style = self.negative_style.unwrap_or(self.style);
+                let height = min(
+                    (value.unsigned_abs() * rows + total / 2) / total,
+                    rows - positive_rows,
+                ) as u16;
+                for y in zero..zero + height {
+                    for x in x..x + self.bar_width {
+                        buf.get_mut(x, y).set_symbol(bar::FULL).set_style(style);
+                    }
+                }
+                if height > 0 && label_width < self.bar_width {
+                    buf.set_string(label_x, zero, &value_label, &self.value_style);
+                }
+            }
+            buf.set_stringn(
+                x,
+                area.bottom() - 1,
+                label,
+                self.bar_width as usize,
+                &self.label_style,
+            );
+        }
+    }
+}
+
 impl<'a> Widget for BarChart<'a> {
     fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
         let chart_area = match self.block {
@@ -123,68 +313,193 @@ impl<'a> Widget for BarChart<'a> {
 
         self.background(&chart_area, buf, self.style.bg);
 
+        if !self.signed_data.is_empty() {
+            self.draw_signed(&chart_area, buf);
+            return;
+        }
+
+        let (bars, labels) = self.bars(&chart_area);
         let max = self.max
-            .unwrap_or_else(|| self.data.iter().fold(0, |acc, &(_, v)| max(v, acc)));
-        let max_index = min(
-            (chart_area.width / (self.bar_width + self.bar_gap)) as usize,
-            self.data.len(),
-        );
-        let mut data = self.data
-            .iter()
-            .take(max_index)
-            .map(|&(l, v)| (l, v * u64::from(chart_area.height) * 8 / max))
-            .collect::<Vec<(&str, u64)>>();
+            .unwrap_or_else(|| bars.iter().fold(0, |acc, b| max(b.value, acc)));
+        let mut data = bars.iter()
+            .map(|b| b.value * u64::from(chart_area.height) * 8 / max)
+            .collect::<Vec<u64>>();
+        // Number of rows covered by each bar (the last row is reserved for the labels)
+        let heights = data.iter()
+            .map(|v| min(v.div_ceil(8), u64::from(chart_area.height - 1)) as u16)
+            .collect::<Vec<u16>>();
         for j in (0..chart_area.height - 1).rev() {
-            for (i, d) in data.iter_mut().enumerate() {
-                let symbol = match d.1 {
-                    0 => " ",
-                    1 => bar::ONE_EIGHTH,
-                    2 => bar::ONE_QUATER,
-                    3 => bar::THREE_EIGHTHS,
-                    4 => bar::HALF,
-                    5 => bar::FIVE_EIGHTHS,
-                    6 => bar::THREE_QUATERS,
-                    7 => bar::SEVEN_EIGHTHS,
-                    _ => bar::FULL,
-                };
+            for (bar, d) in bars.iter().zip(data.iter_mut()) {
+                let symbol = bar_symbol(*d);
 
                 for x in 0..self.bar_width {
-                    buf.get_mut(
-                        chart_area.left() + i as u16 * (self.bar_width + self.bar_gap) + x,
-                        chart_area.top() + j,
-                    ).set_symbol(symbol)
-                        .set_style(self.style);
+                    buf.get_mut(bar.x + x, chart_area.top() + j)
+                        .set_symbol(symbol)
+                        .set_style(bar.style);
                 }
 
-                if d.1 > 8 {
-                    d.1 -= 8;
+                if *d > 8 {
+                    *d -= 8;
                 } else {
-                    d.1 = 0;
+                    *d = 0;
                 }
             }
         }
 
-        for (i, &(label, value)) in self.data.iter().take(max_index).enumerate() {
-            if value != 0 {
-                let value_label = &self.values[i];
-                let width = value_label.width() as u16;
+        for (bar, &height) in bars.iter().zip(heights.iter()) {
+            // Values on top of the bars need a bar to stand on, others are written over the bars
+            if bar.value != 0 && (height != 0 || !self.value_on_top) {
+                let width = bar.value_label.width() as u16;
                 if width < self.bar_width {
+                    let y = if self.value_on_top {
+                        chart_area.bottom() - 1 - height
+                    } else {
+                        chart_area.bottom() - 2
+                    };
                     buf.set_string(
-                        chart_area.left() + i as u16 * (self.bar_width + self.bar_gap)
-                            + (self.bar_width - width) / 2,
-                        chart_area.bottom() - 2,
-                        value_label,
+                        bar.x + (self.bar_width - width) / 2,
+                        y,
+                        &bar.value_label,
                         &self.value_style,
                     );
                 }
             }
+        }
+
+        for &(x, width, label) in &labels {
             buf.set_stringn(
-                chart_area.left() + i as u16 * (self.bar_width + self.bar_gap),
+                x,
                 chart_area.bottom() - 1,
                 label,
-                self.bar_width as usize,
+                width as usize,
                 &self.label_style,
             );
         }
+
+        if !self.groups.is_empty() {
+            if let Some(inner_width) = self.series.iter().map(|s| s.width() as u16).max() {
+                let legend_width = inner_width + 2;
+                let legend_height = self.series.len() as u16 + 2;
+                if legend_width < chart_area.width / 3 && legend_height < chart_area.height / 3 {
+                    let legend_area = Rect::new(
+                        chart_area.right() - legend_width,
+                        chart_area.top(),
+                        legend_width,
+                        legend_height,
+                    );
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .draw(&legend_area, buf);
+                    for (i, name) in self.series.iter().enumerate() {
+                        buf.set_string(
+                            legend_area.x + 1,
+                            legend_area.y + 1 + i as u16,
+                            name,
+                            &self.series_style(i),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_printed_at_the_top_of_the_bars() {
+        let area = Rect::new(0, 0, 8, 5);
+        let mut buf = Buffer::empty(area);
+        // 4 rows are available for the bars, a value of 2 out of 4 covers 3 rows once scaled on
+        // the whole height of the widget.
+        BarChart::default()
+            .bar_width(3)
+            .data(&[("a", 2), ("b", 4)])
+            .max(4)
+            .value_on_top(true)
+            .draw(&area, &mut buf);
+        assert_eq!(buf.get(1, 1).symbol, "2");
+        assert_eq!(buf.get(5, 0).symbol, "4");
+    }
+
+    #[test]
+    fn values_of_bars_too_small_to_show_are_printed_above_the_labels() {
+        let area = Rect::new(0, 0, 8, 5);
+        let mut buf = Buffer::empty(area);
+        BarChart::default()
+            .bar_width(3)
+            .data(&[("a", 1), ("b", 100)])
+            .draw(&area, &mut buf);
+        assert_eq!(buf.get(1, 3).symbol, "1");
+    }
+
+    #[test]
+    fn negative_values_are_drawn_below_the_zero_line() {
+        let area = Rect::new(0, 0, 4, 7);
+        let mut buf = Buffer::empty(area);
+        let negative = Style::default().fg(Color::Red);
+        // 6 rows are shared between the positive and negative values, 4 rows for 2 and 2 rows
+        // for -1.
+        BarChart::default()
+            .signed_data(&[("a", 2), ("b", -1)])
+            .negative_style(negative)
+            .draw(&area, &mut buf);
+
+        let column = |x| (0..7).map(|y| buf.get(x, y).symbol.as_str()).collect::<String>();
+        assert_eq!(column(0), "████  a");
+        assert_eq!(column(2), "    ██b");
+        assert_eq!(buf.get(2, 4).style, negative);
+        assert_eq!(buf.get(0, 0).style, Style::default());
+    }
+
+    #[test]
+    fn groups_are_drawn_as_adjacent_bars() {
+        let area = Rect::new(0, 0, 12, 4);
+        let mut buf = Buffer::empty(area);
+        BarChart::default()
+            .bar_width(2)
+            .bar_gap(1)
+            .groups(&[("g1", &[4, 4]), ("g2", &[4, 4]), ("g3", &[4, 4])])
+            .palette(&[Color::Red, Color::Blue])
+            .draw(&area, &mut buf);
+
+        for &(x, color) in &[
+            (0, Color::Red),
+            (1, Color::Red),
+            (2, Color::Blue),
+            (3, Color::Blue),
+            (5, Color::Red),
+            (7, Color::Blue),
+        ] {
+            assert_eq!(buf.get(x, 0).symbol, bar::FULL);
+            assert_eq!(buf.get(x, 0).style.fg, color);
+        }
+        // Gap between the groups
+        assert_eq!(buf.get(4, 0).symbol, " ");
+        // The third group does not fit
+        assert_eq!(buf.get(10, 0).symbol, " ");
+        assert_eq!(buf.get(0, 3).symbol, "g");
+        assert_eq!(buf.get(5, 3).symbol, "g");
+    }
+
+    #[test]
+    fn series_of_groups_are_named_in_a_legend() {
+        let area = Rect::new(0, 0, 12, 15);
+        let mut buf = Buffer::empty(area);
+        BarChart::default()
+            .bar_width(2)
+            .groups(&[("g1", &[2, 4])])
+            .series(&["a", "b"])
+            .palette(&[Color::Red, Color::Blue])
+            .max(4)
+            .draw(&area, &mut buf);
+
+        let rows = buf.to_string();
+        let legend = rows.lines().take(5).map(|l| l.chars().skip(9).collect());
+        assert_eq!(legend.collect::<Vec<String>>(), vec!["┌─┐", "│a│", "│b│", "└─┘", "   "]);
+        assert_eq!(buf.get(10, 1).style.fg, Color::Red);
+        assert_eq!(buf.get(10, 2).style.fg, Color::Blue);
     }
 }
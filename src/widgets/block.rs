@@ -1,9 +1,33 @@
+use std::cmp::min;
+
+use unicode_width::UnicodeWidthStr;
+
 use buffer::Buffer;
-use layout::Rect;
-use style::Style;
+use layout::{Alignment, Rect};
+use style::{Color, Style};
 use widgets::{Borders, Widget};
 use symbols::line;
 
+/// The kind of lines used to draw the borders of a block
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderType {
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+}
+
+impl BorderType {
+    pub fn line_symbols(&self) -> line::Set {
+        match *self {
+            BorderType::Plain => line::NORMAL,
+            BorderType::Rounded => line::ROUNDED,
+            BorderType::Double => line::DOUBLE,
+            BorderType::Thick => line::THICK,
+        }
+    }
+}
+
 /// Base widget to be used with all upper level ones. It may be used to display a box border around
 /// the widget and/or add a title.
 ///
@@ -28,12 +52,26 @@ pub struct Block<'a> {
     title: Option<&'a str>,
     /// Title style
     title_style: Style,
+    /// Alignment of the title within the top border
+    title_alignment: Alignment,
+    /// Whether the title is set within the top border between junctions
+    title_embedded: bool,
     /// Visible borders
     borders: Borders,
     /// Border style
     border_style: Style,
+    /// Kind of lines of the borders
+    border_type: BorderType,
+    /// Whether the block has the focus, its borders being then highlighted
+    focused: bool,
+    /// Border style used when the block has the focus
+    focused_border_style: Style,
+    /// Kind of lines of the borders used when the block has the focus
+    focused_border_type: Option<BorderType>,
     /// Widget style
     style: Style,
+    /// Style of the shadow drawn on the right and at the bottom of the block
+    shadow: Option<Style>,
 }
 
 impl<'a> Default for Block<'a> {
@@ -41,9 +79,16 @@ impl<'a> Default for Block<'a> {
         Block {
             title: None,
             title_style: Default::default(),
+            title_alignment: Alignment::Left,
+            title_embedded: false,
             borders: Borders::NONE,
             border_style: Default::default(),
+            border_type: BorderType::Plain,
+            focused: false,
+            focused_border_style: Style::default().fg(Color::Yellow),
+            focused_border_type: None,
             style: Default::default(),
+            shadow: None,
         }
     }
 }
@@ -59,11 +104,47 @@ impl<'a> Block<'a> {
         self
     }
 
+    pub fn title_alignment(mut self, alignment: Alignment) -> Block<'a> {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Set the title within the top border, e.g. `┌┤ Title ├─┐`, instead of writing it over the
+    /// border line. This needs the top border to be visible.
+    pub fn title_embedded(mut self, embedded: bool) -> Block<'a> {
+        self.title_embedded = embedded;
+        self
+    }
+
     pub fn border_style(mut self, style: Style) -> Block<'a> {
         self.border_style = style;
         self
     }
 
+    pub fn border_type(mut self, border_type: BorderType) -> Block<'a> {
+        self.border_type = border_type;
+        self
+    }
+
+    /// Highlight the borders of the block, e.g. to show which panel has the keyboard focus
+    pub fn focused(mut self, focused: bool) -> Block<'a> {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the style of the borders when the block has the focus (yellow by default)
+    pub fn focused_border_style(mut self, style: Style) -> Block<'a> {
+        self.focused_border_style = style;
+        self
+    }
+
+    /// Set the kind of lines of the borders when the block has the focus, the borders keep their
+    /// usual kind of lines by default
+    pub fn focused_border_type(mut self, border_type: BorderType) -> Block<'a> {
+        self.focused_border_type = Some(border_type);
+        self
+    }
+
     pub fn style(mut self, style: Style) -> Block<'a> {
         self.style = style;
         self
@@ -74,6 +155,13 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Draw a shadow one cell below and on the right of the block using the given style. The
+    /// shadow lies outside of the area of the block and keeps the symbols already drawn there.
+    pub fn shadow(mut self, style: Style) -> Block<'a> {
+        self.shadow = Some(style);
+        self
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     pub fn inner(&self, area: &Rect) -> Rect {
         if area.width < 2 || area.height < 2 {
@@ -96,68 +184,92 @@ impl<'a> Block<'a> {
         }
         inner
     }
-}
 
-impl<'a> Widget for Block<'a> {
-    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+    /// Draw the borders, the title and the shadow of the block while leaving the cells of its
+    /// inner area untouched, e.g. to frame some content already drawn in the buffer.
+    pub fn render_frame_only(&self, area: &Rect, buf: &mut Buffer) {
         if area.width < 2 || area.height < 2 {
             return;
         }
 
-        self.background(area, buf, self.style.bg);
+        if let Some(shadow) = self.shadow {
+            let bounds = *buf.area();
+            let x = area.right();
+            if x < bounds.right() {
+                for y in area.top() + 1..min(area.bottom() + 1, bounds.bottom()) {
+                    buf.get_mut(x, y).set_style(shadow);
+                }
+            }
+            let y = area.bottom();
+            if y < bounds.bottom() {
+                for x in area.left() + 1..min(area.right() + 1, bounds.right()) {
+                    buf.get_mut(x, y).set_style(shadow);
+                }
+            }
+        }
+
+        let (border_style, border_type) = if self.focused {
+            (
+                self.focused_border_style,
+                self.focused_border_type.unwrap_or(self.border_type),
+            )
+        } else {
+            (self.border_style, self.border_type)
+        };
+        let symbols = border_type.line_symbols();
 
         // Sides
         if self.borders.intersects(Borders::LEFT) {
             for y in area.top()..area.bottom() {
                 buf.get_mut(area.left(), y)
-                    .set_symbol(line::VERTICAL)
-                    .set_style(self.border_style);
+                    .set_symbol(symbols.vertical)
+                    .set_style(border_style);
             }
         }
         if self.borders.intersects(Borders::TOP) {
             for x in area.left()..area.right() {
                 buf.get_mut(x, area.top())
-                    .set_symbol(line::HORIZONTAL)
-                    .set_style(self.border_style);
+                    .set_symbol(symbols.horizontal)
+                    .set_style(border_style);
             }
         }
         if self.borders.intersects(Borders::RIGHT) {
             let x = area.right() - 1;
             for y in area.top()..area.bottom() {
                 buf.get_mut(x, y)
-                    .set_symbol(line::VERTICAL)
-                    .set_style(self.border_style);
+                    .set_symbol(symbols.vertical)
+                    .set_style(border_style);
             }
         }
         if self.borders.intersects(Borders::BOTTOM) {
             let y = area.bottom() - 1;
             for x in area.left()..area.right() {
                 buf.get_mut(x, y)
-                    .set_symbol(line::HORIZONTAL)
-                    .set_style(self.border_style);
+                    .set_symbol(symbols.horizontal)
+                    .set_style(border_style);
             }
         }
 
         // Corners
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
             buf.get_mut(area.left(), area.top())
-                .set_symbol(line::TOP_LEFT)
-                .set_style(self.border_style);
+                .set_symbol(symbols.top_left)
+                .set_style(border_style);
         }
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
             buf.get_mut(area.right() - 1, area.top())
-                .set_symbol(line::TOP_RIGHT)
-                .set_style(self.border_style);
+                .set_symbol(symbols.top_right)
+                .set_style(border_style);
         }
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
             buf.get_mut(area.left(), area.bottom() - 1)
-                .set_symbol(line::BOTTOM_LEFT)
-                .set_style(self.border_style);
+                .set_symbol(symbols.bottom_left)
+                .set_style(border_style);
         }
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
             buf.get_mut(area.right() - 1, area.bottom() - 1)
-                .set_symbol(line::BOTTOM_RIGHT)
-                .set_style(self.border_style);
+                .set_symbol(symbols.bottom_right)
+                .set_style(border_style);
         }
 
         if area.width > 2 {
@@ -173,14 +285,208 @@ impl<'a> Widget for Block<'a> {
                     0
                 };
                 let width = area.width - lx - rx;
-                buf.set_stringn(
-                    area.left() + lx,
-                    area.top(),
-                    title,
-                    width as usize,
-                    &self.title_style,
-                );
+                let embedded =
+                    self.title_embedded && self.borders.intersects(Borders::TOP) && width >= 5;
+                if embedded {
+                    let title_width = min(title.width() as u16, width - 4);
+                    let x = area.left() + lx
+                        + self.title_alignment.offset(title_width + 4, width);
+                    let y = area.top();
+                    buf.set_string(x, y, symbols.vertical_left, &border_style);
+                    buf.set_string(x + 1, y, " ", &border_style);
+                    buf.set_stringn(x + 2, y, title, title_width as usize, &self.title_style);
+                    buf.set_string(x + 2 + title_width, y, " ", &border_style);
+                    buf.set_string(x + 3 + title_width, y, symbols.vertical_right, &border_style);
+                } else {
+                    let offset = self
+                        .title_alignment
+                        .offset(title.width() as u16, width);
+                    buf.set_stringn(
+                        area.left() + lx + offset,
+                        area.top(),
+                        title,
+                        (width - offset) as usize,
+                        &self.title_style,
+                    );
+                }
             }
         }
     }
 }
+
+impl<'a> Widget for Block<'a> {
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+
+        self.background(area, buf, self.style.bg);
+        self.render_frame_only(area, buf);
+    }
+}
+
+impl Buffer {
+    /// Fill the given area with blank cells of the given style and, if a border type is given,
+    /// draw borders of that type all around it. The borders are drawn the way a [`Block`] with
+    /// all its borders would draw them.
+    ///
+    /// [`Block`]: ../widgets/struct.Block.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// # use tui::widgets::BorderType;
+    /// # fn main() {
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+    /// let area = *buf.area();
+    /// buf.draw_box(&area, &Style::default(), &Style::default(), Some(BorderType::Rounded));
+    /// assert_eq!(buf.to_string(), "╭──╮\n│  │\n╰──╯");
+    /// # }
+    /// ```
+    pub fn draw_box(
+        &mut self,
+        area: &Rect,
+        fill_style: &Style,
+        border_style: &Style,
+        border_type: Option<BorderType>,
+    ) {
+        self.clear_with(area, " ", fill_style);
+        if let Some(border_type) = border_type {
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(border_type)
+                .border_style(*border_style)
+                .style(*fill_style)
+                .draw(area, self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_draws_boxes() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+        let fill = Style::default().bg(Color::Blue);
+        let border = Style::default().fg(Color::Red);
+        buf.draw_box(&Rect::new(1, 1, 4, 4), &fill, &border, Some(BorderType::Double));
+
+        assert_eq!(buf.to_string(), "      \n ╔══╗ \n ║  ║ \n ║  ║ \n ╚══╝ \n      ");
+        for &(x, y) in &[(1, 1), (2, 1), (4, 2), (1, 3), (3, 4)] {
+            assert_eq!(buf.get(x, y).style, border);
+        }
+        for &(x, y) in &[(2, 2), (3, 2), (2, 3), (3, 3)] {
+            assert_eq!(buf.get(x, y).style, fill);
+        }
+        assert_eq!(buf.get(0, 0).style, Style::default());
+    }
+
+    #[test]
+    fn boxes_without_borders_are_only_filled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        let fill = Style::default().bg(Color::Blue);
+        buf.get_mut(1, 1).set_symbol("x");
+        buf.draw_box(&Rect::new(1, 1, 2, 2), &fill, &Style::default(), None);
+
+        assert_eq!(buf.to_string(), "    \n    \n    \n    ");
+        for &(x, y) in &[(1, 1), (2, 1), (1, 2), (2, 2)] {
+            assert_eq!(buf.get(x, y).style, fill);
+        }
+        assert_eq!(buf.get(3, 3).style, Style::default());
+    }
+
+    #[test]
+    fn focused_blocks_have_highlighted_borders() {
+        let area = Rect::new(0, 0, 3, 3);
+        let normal = Style::default().fg(Color::White);
+        let highlight = Style::default().fg(Color::Cyan);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(normal)
+            .focused_border_style(highlight)
+            .focused_border_type(BorderType::Thick);
+
+        let mut buf = Buffer::empty(area);
+        block.focused(false).draw(&area, &mut buf);
+        assert_eq!(buf.get(0, 0).symbol, line::TOP_LEFT);
+        assert_eq!(buf.get(1, 2).style, normal);
+
+        let mut buf = Buffer::empty(area);
+        block.focused(true).draw(&area, &mut buf);
+        assert_eq!(buf.get(0, 0).symbol, line::THICK_TOP_LEFT);
+        assert_eq!(buf.get(1, 2).style, highlight);
+    }
+
+    #[test]
+    fn shadow_is_drawn_outside_of_the_block() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let shadow = Style::default().bg(Color::DarkGray);
+        Block::default()
+            .borders(Borders::ALL)
+            .shadow(shadow)
+            .draw(&Rect::new(0, 0, 4, 3), &mut buf);
+
+        for &(x, y) in &[(4, 1), (4, 2), (4, 3), (1, 3), (2, 3), (3, 3)] {
+            assert_eq!(buf.get(x, y).style, shadow);
+        }
+        for &(x, y) in &[(4, 0), (0, 3), (5, 3), (3, 2)] {
+            assert_ne!(buf.get(x, y).style, shadow);
+        }
+
+        // The shadow is clamped to the buffer
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Block::default()
+            .shadow(shadow)
+            .draw(&Rect::new(0, 0, 4, 3), &mut buf);
+    }
+
+    #[test]
+    fn frame_only_rendering_keeps_the_inner_cells() {
+        let area = Rect::new(0, 0, 4, 3);
+        let content = Style::default().bg(Color::Green);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(1, 1, "ab", &content);
+        Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().bg(Color::Blue))
+            .render_frame_only(&area, &mut buf);
+
+        assert_eq!(buf.to_string(), "┌──┐\n│ab│\n└──┘");
+        assert_eq!(buf.get(1, 1).style, content);
+        assert_eq!(buf.get(2, 1).style, content);
+    }
+
+    #[test]
+    fn embedded_titles_are_bracketed_by_junctions() {
+        let area = Rect::new(0, 0, 12, 2);
+        for &(border_type, expected) in &[
+            (BorderType::Plain, "┌┤ ab ├────┐\n└──────────┘"),
+            (BorderType::Rounded, "╭┤ ab ├────╮\n╰──────────╯"),
+            (BorderType::Double, "╔╣ ab ╠════╗\n╚══════════╝"),
+        ] {
+            let mut buf = Buffer::empty(area);
+            Block::default()
+                .title("ab")
+                .title_embedded(true)
+                .borders(Borders::ALL)
+                .border_type(border_type)
+                .draw(&Rect::new(0, 0, 12, 2), &mut buf);
+            assert_eq!(buf.to_string(), expected);
+        }
+
+        // Titles too long to fit are shortened to keep the junctions
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        Block::default()
+            .title("abcdef")
+            .title_embedded(true)
+            .borders(Borders::ALL)
+            .draw(&Rect::new(0, 0, 8, 2), &mut buf);
+        assert_eq!(buf.to_string(), "┌┤ ab ├┐\n└──────┘");
+    }
+}
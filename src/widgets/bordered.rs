@@ -0,0 +1,83 @@
+use buffer::Buffer;
+use layout::Rect;
+use widgets::{Block, Widget};
+
+/// Draws a block and then the wrapped widget inside of it, for widgets which cannot be given a
+/// block of their own.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::buffer::Buffer;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Block, Bordered, Borders, Gauge, Widget};
+/// # fn main() {
+/// let mut gauge = Gauge::default();
+/// gauge.percent(100).label("ok");
+/// let area = Rect::new(0, 0, 6, 3);
+/// let mut buf = Buffer::empty(area);
+/// Bordered::new(Block::default().borders(Borders::ALL), gauge).draw(&area, &mut buf);
+/// assert_eq!(buf.to_string(), "┌────┐\n│ ok │\n└────┘");
+/// # }
+/// ```
+pub struct Bordered<'a, W> {
+    block: Block<'a>,
+    widget: W,
+}
+
+impl<'a, W> Bordered<'a, W>
+where
+    W: Widget,
+{
+    pub fn new(block: Block<'a>, widget: W) -> Bordered<'a, W> {
+        Bordered { block, widget }
+    }
+
+    /// Returns the wrapped widget, e.g. to update it between two frames
+    pub fn widget_mut(&mut self) -> &mut W {
+        &mut self.widget
+    }
+}
+
+impl<'a, W> Widget for Bordered<'a, W>
+where
+    W: Widget,
+{
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        self.block.draw(area, buf);
+        let inner = self.block.inner(area);
+        if inner.width > 0 && inner.height > 0 {
+            self.widget.draw(&inner, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use symbols;
+    use widgets::{Axis, Borders, Chart, Dataset};
+
+    #[test]
+    fn wrapped_widgets_are_drawn_inside_the_block() {
+        let data = [(0.0, 0.0), (10.0, 10.0)];
+        let datasets = [Dataset::default().data(&data)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let block = Block::default().title("Chart").borders(Borders::ALL);
+        let area = Rect::new(0, 0, 12, 6);
+        let mut buf = Buffer::empty(area);
+        Bordered::new(block, chart).draw(&area, &mut buf);
+
+        let lines = buf.to_string();
+        let lines = lines.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], "┌Chart─────┐");
+        assert_eq!(lines[5], "└──────────┘");
+        assert_eq!(buf.get(1, 4).symbol, symbols::DOT);
+        assert_eq!(buf.get(10, 1).symbol, symbols::DOT);
+    }
+}
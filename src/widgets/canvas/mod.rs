@@ -38,7 +38,7 @@ pub struct Label<'a> {
 }
 
 struct Layer {
-    string: String,
+    cells: Vec<u16>,
     colors: Vec<Color>,
 }
 
@@ -57,7 +57,7 @@ impl Grid {
 
     fn save(&self) -> Layer {
         Layer {
-            string: String::from_utf16(&self.cells).unwrap(),
+            cells: self.cells.clone(),
             colors: self.colors.clone(),
         }
     }
@@ -218,36 +218,65 @@ where
         self
     }
 
-    pub fn background_color(&'a mut self, color: Color) -> &mut Canvas<'a, F> {
+    pub fn background_color(&mut self, color: Color) -> &mut Canvas<'a, F> {
         self.background_color = color;
         self
     }
-}
 
-impl<'a, F> Widget for Canvas<'a, F>
-where
-    F: Fn(&mut Context),
-{
-    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+    /// Returns the braille dots painted in each cell of the given area, row by row, all the
+    /// layers being merged, e.g. to check precisely where the shapes put their points. Each cell
+    /// is given as the set of its dots (see `DOTS` for the bit of each dot).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::layout::Rect;
+    /// # use tui::widgets::canvas::{Canvas, Points, DOTS};
+    /// # use tui::style::Color;
+    /// # fn main() {
+    /// let mut canvas = Canvas::default();
+    /// canvas
+    ///     .x_bounds([0.0, 1.0])
+    ///     .y_bounds([0.0, 1.0])
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Points {
+    ///             coords: &[(0.0, 1.0)],
+    ///             color: Color::Reset,
+    ///         });
+    ///     });
+    /// let dots = canvas.braille_dots(&Rect::new(0, 0, 2, 2));
+    /// assert_eq!(dots, vec![vec![DOTS[0][0] as u8, 0], vec![0, 0]]);
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "testing"))]
+    pub fn braille_dots(&self, area: &Rect) -> Vec<Vec<u8>> {
         let canvas_area = match self.block {
-            Some(ref mut b) => {
-                b.draw(area, buf);
-                b.inner(area)
-            }
+            Some(ref b) => b.inner(area),
             None => *area,
         };
-
         let width = canvas_area.width as usize;
-        let height = canvas_area.height as usize;
+        let mut dots = vec![vec![0; width]; canvas_area.height as usize];
+        if let Some(ctx) = self.context(&canvas_area) {
+            for layer in ctx.layers {
+                for (i, &cell) in layer.cells.iter().enumerate() {
+                    dots[i / width][i % width] |= (cell - BRAILLE_OFFSET) as u8;
+                }
+            }
+        }
+        dots
+    }
 
-        if let Some(ref painter) = self.painter {
+    /// Returns the context painted over the given area, once all its layers have been pushed
+    fn context(&self, area: &Rect) -> Option<Context<'_>> {
+        self.painter.as_ref().map(|painter| {
             // Create a blank context that match the size of the terminal
             let mut ctx = Context {
                 x_bounds: self.x_bounds,
                 y_bounds: self.y_bounds,
-                width: canvas_area.width,
-                height: canvas_area.height,
-                grid: Grid::new(width, height),
+                width: area.width,
+                height: area.height,
+                grid: Grid::new(area.width as usize, area.height as usize),
                 dirty: false,
                 layers: Vec::new(),
                 labels: Vec::new(),
@@ -255,17 +284,38 @@ where
             // Paint to this context
             painter(&mut ctx);
             ctx.finish();
+            ctx
+        })
+    }
+}
+
+impl<'a, F> Widget for Canvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        let canvas_area = match self.block {
+            Some(ref mut b) => {
+                b.draw(area, buf);
+                b.inner(area)
+            }
+            None => *area,
+        };
+
+        let width = canvas_area.width as usize;
 
+        if let Some(ctx) = self.context(&canvas_area) {
             // Retreive painted points for each layer
             for layer in ctx.layers {
-                for (i, (ch, color)) in layer
-                    .string
-                    .chars()
+                for (i, (&cell, color)) in layer
+                    .cells
+                    .iter()
                     .zip(layer.colors.into_iter())
                     .enumerate()
                 {
-                    if ch != BRAILLE_BLANK {
+                    if cell != BRAILLE_OFFSET {
                         let (x, y) = (i % width, i / width);
+                        let ch = char::from_u32(u32::from(cell)).unwrap_or(BRAILLE_BLANK);
                         buf.get_mut(x as u16 + canvas_area.left(), y as u16 + canvas_area.top())
                             .set_char(ch)
                             .set_fg(color)
@@ -1,12 +1,15 @@
+use std::borrow::Cow;
 use std::cmp::max;
+use std::mem;
 
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use widgets::{Block, Borders, Widget};
-use widgets::canvas::{Canvas, Points};
+use widgets::{Block, BorderType, Borders, Projection, Scale, Widget};
+use widgets::canvas::{Canvas, Context, Points};
 use buffer::Buffer;
-use layout::Rect;
-use style::Style;
+use layout::{Alignment, Rect};
+use style::{Color, Style};
 use symbols;
 
 /// An X or Y axis for the chart widget
@@ -20,12 +23,47 @@ where
     title_style: Style,
     /// Bounds for the axis (all data points outside these limits will not be represented)
     bounds: [f64; 2],
+    /// Bounds fixed by the user when the others are computed from the data
+    partial_bounds: Option<[Option<f64>; 2]>,
+    /// How the values are distributed along the axis
+    scale: Scale,
     /// A list of labels to put to the left or below the axis
-    labels: Option<&'a [L]>,
+    pub(crate) labels: Option<&'a [L]>,
+    /// A list of labels to put next to specific values of the axis
+    labels_at: Option<&'a [(f64, L)]>,
     /// The labels' style
-    labels_style: Style,
+    pub(crate) labels_style: Style,
     /// The style used to draw the axis itself
     style: Style,
+    /// How the labels are written (only used by the x axis)
+    label_rotation: Rotation,
+    /// Whether a tick is drawn on the axis line at the position of each label
+    ticks_visible: bool,
+    /// Symbol repeated along the axis line instead of the default line
+    line_symbol: Option<&'a str>,
+    /// Maximum number of labels displayed
+    max_labels: Option<usize>,
+    /// Number of numeric labels generated from the bounds
+    auto_labels: Option<usize>,
+    /// How the generated labels are formatted
+    label_format: NumberFormat,
+    /// Whether the precision of the generated labels is chosen from the gap between them
+    auto_precision: bool,
+    /// Text appended to the generated labels
+    unit: &'a str,
+    /// Color of the line drawn across the graph area at the zero of the axis
+    zero_line_color: Option<Color>,
+}
+
+/// Orientation of the labels of an axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rotation {
+    /// The labels are written from left to right
+    Horizontal,
+    /// The labels are written from top to bottom, one character per row
+    Vertical,
+    /// The labels are written from the top left to the bottom right, one character per row
+    Diagonal,
 }
 
 impl<'a, L> Default for Axis<'a, L>
@@ -37,9 +75,21 @@ where
             title: None,
             title_style: Default::default(),
             bounds: [0.0, 0.0],
+            partial_bounds: None,
+            scale: Scale::Linear,
             labels: None,
+            labels_at: None,
             labels_style: Default::default(),
             style: Default::default(),
+            label_rotation: Rotation::Horizontal,
+            ticks_visible: false,
+            line_symbol: None,
+            max_labels: None,
+            auto_labels: None,
+            label_format: NumberFormat::default(),
+            auto_precision: true,
+            unit: "",
+            zero_line_color: None,
         }
     }
 }
@@ -60,14 +110,124 @@ where
 
     pub fn bounds(mut self, bounds: [f64; 2]) -> Axis<'a, L> {
         self.bounds = bounds;
+        self.partial_bounds = None;
+        self
+    }
+
+    /// Set some of the bounds, the `None` ones being computed by the chart from its datasets.
+    /// Computed bounds leave a margin of 5% of the span of the axis beyond the extreme values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Axis;
+    /// // The axis starts at 0 and grows with the data
+    /// Axis::<&str>::default().bounds_with_auto([Some(0.0), None]);
+    /// ```
+    pub fn bounds_with_auto(mut self, bounds: [Option<f64>; 2]) -> Axis<'a, L> {
+        self.partial_bounds = Some(bounds);
+        self
+    }
+
+    /// Set how the values are distributed along the axis (linearly by default)
+    pub fn scale(mut self, scale: Scale) -> Axis<'a, L> {
+        self.scale = scale;
         self
     }
 
+    /// Labels evenly spread along the axis, the first one being at its start and the last one at
+    /// its end.
     pub fn labels(mut self, labels: &'a [L]) -> Axis<'a, L> {
         self.labels = Some(labels);
+        self.labels_at = None;
+        self.auto_labels = None;
+        self
+    }
+
+    /// Labels placed next to the given values of the axis. Labels whose value is outside of the
+    /// bounds are not displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Axis;
+    /// Axis::default()
+    ///     .bounds([0.0, 137.0])
+    ///     .labels_at(&[(0.0, "0"), (50.0, "50"), (100.0, "100")]);
+    /// ```
+    pub fn labels_at(mut self, labels: &'a [(f64, L)]) -> Axis<'a, L> {
+        self.labels_at = Some(labels);
+        self.labels = None;
+        self.auto_labels = None;
+        self
+    }
+
+    /// Generate `count` numeric labels evenly spread along the axis from its bounds, instead of
+    /// giving them explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Axis;
+    /// // Labels "0.000", "0.025", "0.050", "0.075" and "0.100"
+    /// Axis::<&str>::default().bounds([0.0, 0.1]).auto_labels(5);
+    /// ```
+    pub fn auto_labels(mut self, count: usize) -> Axis<'a, L> {
+        self.auto_labels = Some(count);
+        self.labels = None;
+        self.labels_at = None;
+        self
+    }
+
+    /// Set how the generated labels are formatted
+    pub fn label_format(mut self, format: NumberFormat) -> Axis<'a, L> {
+        self.label_format = format;
+        self
+    }
+
+    /// Choose the number of decimal places of the generated labels from the gap between them, so
+    /// that neighbouring labels differ (enabled by default). When disabled, or when the label
+    /// format has a precision of its own, the format is used as is.
+    pub fn auto_precision(mut self, enabled: bool) -> Axis<'a, L> {
+        self.auto_precision = enabled;
+        self
+    }
+
+    /// Set a unit appended to each generated label, e.g. `"ms"` or `" MB"`
+    pub fn unit(mut self, unit: &'a str) -> Axis<'a, L> {
+        self.unit = unit;
         self
     }
 
+    /// Returns the labels generated from the bounds, if any
+    fn generated_labels(&self) -> Option<Vec<String>> {
+        let count = self.auto_labels?;
+        let projection = Projection::new(self.bounds, 0, count as u16).scale(self.scale);
+        let values = (0..count)
+            .map(|i| {
+                if count > 1 {
+                    projection.inverse(i as f32)
+                } else {
+                    self.bounds[0]
+                }
+            })
+            .collect::<Vec<f64>>();
+        let mut format = self.label_format;
+        if self.auto_precision && format.precision.is_none() {
+            let gap = values
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs())
+                .fold(self.bounds[1] - self.bounds[0], f64::min);
+            format.precision = Some(precision_for(gap));
+        }
+        Some(
+            values
+                .iter()
+                .map(|&v| format.format(v) + self.unit)
+                .collect(),
+        )
+    }
+
     pub fn labels_style(mut self, style: Style) -> Axis<'a, L> {
         self.labels_style = style;
         self
@@ -77,14 +237,331 @@ where
         self.style = style;
         self
     }
+
+    /// Rotate the labels so that more of them fit below the x axis. Rotated labels are written
+    /// under their tick and take as many rows as characters in the longest label.
+    pub fn label_rotation(mut self, rotation: Rotation) -> Axis<'a, L> {
+        self.label_rotation = rotation;
+        self
+    }
+
+    /// Draw a tick on the axis line at the position of each label
+    pub fn ticks_visible(mut self, visible: bool) -> Axis<'a, L> {
+        self.ticks_visible = visible;
+        self
+    }
+
+    /// Set the symbol repeated along the axis line, e.g. `symbols::line::DOUBLE_HORIZONTAL` to
+    /// match the borders of the surrounding block or `"."` for a dotted axis. A plain line is
+    /// drawn by default.
+    pub fn line_symbol(mut self, symbol: &'a str) -> Axis<'a, L> {
+        self.line_symbol = Some(symbol);
+        self
+    }
+
+    /// Display at most the given number of labels, only keeping evenly spaced ones (the first
+    /// label always being displayed). Whatever this limit, the labels of the x axis are thinned
+    /// out the same way when they are too close to each other to be written.
+    pub fn max_labels(mut self, max: usize) -> Axis<'a, L> {
+        self.max_labels = Some(max);
+        self
+    }
+
+    /// Draw a line of the given color across the graph area at the zero of the axis (when it
+    /// lies within the bounds), e.g. to emphasize the origin of signed data. The line is drawn
+    /// over the dot grid and below the reference lines and the datasets.
+    pub fn zero_line_color(mut self, color: Color) -> Axis<'a, L> {
+        self.zero_line_color = Some(color);
+        self
+    }
+
+    /// Returns the bounds of the axis, which are only computed from the data when the axis is
+    /// given to a chart if some of them were left out (see `bounds_with_auto`)
+    pub fn get_bounds(&self) -> [f64; 2] {
+        self.bounds
+    }
+
+    /// Returns the labels put along the axis, if any
+    pub fn get_labels(&self) -> Option<&'a [L]> {
+        self.labels
+    }
+
+    /// Returns a view of the axis with its labels as plain strings
+    fn view<'b>(&'b self) -> AxisView<'b> {
+        AxisView {
+            title: self.title,
+            bounds: self.bounds,
+            scale: self.scale,
+            labels: self.labels
+                .map(|labels| labels.iter().map(|l| Cow::Borrowed(l.as_ref())).collect())
+                .or_else(|| {
+                    self.generated_labels()
+                        .map(|labels| labels.into_iter().map(Cow::Owned).collect())
+                }),
+            labels_at: self.labels_at
+                .map(|labels| labels.iter().map(|(v, l)| (*v, l.as_ref())).collect()),
+            labels_style: self.labels_style,
+            style: self.style,
+            label_rotation: self.label_rotation,
+            ticks_visible: self.ticks_visible,
+            line_symbol: self.line_symbol,
+            max_labels: self.max_labels,
+        }
+    }
+}
+
+/// An axis whose labels are plain strings, so that both axes of a chart can be laid out alike
+/// whatever the type of their labels
+struct AxisView<'b> {
+    title: Option<&'b str>,
+    bounds: [f64; 2],
+    scale: Scale,
+    labels: Option<Vec<Cow<'b, str>>>,
+    labels_at: Option<Vec<(f64, &'b str)>>,
+    labels_style: Style,
+    style: Style,
+    label_rotation: Rotation,
+    ticks_visible: bool,
+    line_symbol: Option<&'b str>,
+    max_labels: Option<usize>,
+}
+
+impl<'b> AxisView<'b> {
+    fn has_labels(&self) -> bool {
+        self.labels.is_some() || self.labels_at.is_some()
+    }
+
+    /// Returns the text of all the labels of the axis
+    fn label_texts(&self) -> Vec<&str> {
+        match (&self.labels, &self.labels_at) {
+            (Some(labels), _) => labels.iter().map(|l| l.as_ref()).collect(),
+            (None, Some(labels)) => labels.iter().map(|&(_, l)| l).collect(),
+            (None, None) => Vec::new(),
+        }
+    }
+
+    /// Returns the labels to display along an axis of the given length and their offsets from
+    /// the start of the axis. The offsets of labels placed at given values match the projection
+    /// of points on the axis, which is done from its end on a vertical axis.
+    fn positioned_labels(&self, length: u16, vertical: bool) -> Vec<(u16, &str)> {
+        let labels = self.all_positioned_labels(length, vertical);
+        match self.max_labels {
+            Some(max) if labels.len() > max => {
+                let step = labels.len().div_ceil(max.max(1));
+                thin_out(labels, step)
+            }
+            _ => labels,
+        }
+    }
+
+    fn all_positioned_labels(&self, length: u16, vertical: bool) -> Vec<(u16, &str)> {
+        match (&self.labels, &self.labels_at) {
+            (Some(labels), _) => {
+                let labels_len = labels.len() as u16;
+                labels
+                    .iter()
+                    .enumerate()
+                    .map(|(i, l)| (label_offset(i as u16, labels_len, length), l.as_ref()))
+                    .collect()
+            }
+            (None, Some(labels)) => labels
+                .iter()
+                .filter(|&&(v, _)| v >= self.bounds[0] && v <= self.bounds[1])
+                .map(|&(v, l)| {
+                    let projection = Projection::new(self.bounds, 0, length)
+                        .scale(self.scale)
+                        .reversed(vertical);
+                    let offset = projection.forward(v) as u16;
+                    if vertical {
+                        (length.saturating_sub(1) - offset, l)
+                    } else {
+                        (offset, l)
+                    }
+                })
+                .collect(),
+            (None, None) => Vec::new(),
+        }
+    }
+
+    /// Returns the number of rows needed to display the labels
+    fn labels_height(&self) -> u16 {
+        match self.label_rotation {
+            Rotation::Horizontal => 1,
+            _ => self
+                .label_texts()
+                .iter()
+                .map(|l| l.graphemes(true).count() as u16)
+                .fold(1, max),
+        }
+    }
+}
+
+/// Describes how to format the numbers displayed in labels
+///
+/// By default numbers are printed using the plain Rust formatting.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::widgets::NumberFormat;
+/// # fn main() {
+/// let us = NumberFormat::default().thousands_sep(',');
+/// assert_eq!(us.format(1234567.5), "1,234,567.5");
+/// let eu = NumberFormat::default()
+///     .decimal_sep(',')
+///     .thousands_sep('.')
+///     .precision(2);
+/// assert_eq!(eu.format(-1234567.5), "-1.234.567,50");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    /// Character separating the integer part from the decimal part
+    pub decimal_sep: char,
+    /// Character inserted between each group of three digits of the integer part
+    pub thousands_sep: Option<char>,
+    /// Number of decimal places (as many as needed if not specified)
+    pub precision: Option<usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> NumberFormat {
+        NumberFormat {
+            decimal_sep: '.',
+            thousands_sep: None,
+            precision: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn decimal_sep(mut self, sep: char) -> NumberFormat {
+        self.decimal_sep = sep;
+        self
+    }
+
+    pub fn thousands_sep(mut self, sep: char) -> NumberFormat {
+        self.thousands_sep = Some(sep);
+        self
+    }
+
+    pub fn precision(mut self, precision: usize) -> NumberFormat {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Formats a number according to this configuration
+    pub fn format(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return format!("{}", value);
+        }
+        let digits = match self.precision {
+            Some(p) => format!("{:.*}", p, value.abs()),
+            None => format!("{}", value.abs()),
+        };
+        let (integer, decimals) = match digits.find('.') {
+            Some(i) => (&digits[..i], Some(&digits[i + 1..])),
+            None => (&digits[..], None),
+        };
+        let mut string = String::with_capacity(digits.len() * 2);
+        if value < 0.0 {
+            string.push('-');
+        }
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                if let Some(sep) = self.thousands_sep {
+                    string.push(sep);
+                }
+            }
+            string.push(c);
+        }
+        if let Some(decimals) = decimals {
+            string.push(self.decimal_sep);
+            string.push_str(decimals);
+        }
+        string
+    }
+}
+
+/// A segment between two points given in data units
+type Segment = ((f64, f64), (f64, f64));
+
+/// The shapes a dataset is plotted with, in data units
+struct Shapes {
+    points: Vec<(f64, f64)>,
+    segments: Vec<Segment>,
+    /// The points of the area below the line, when it is filled with the line marker
+    fill: Vec<(f64, f64)>,
+    /// The symbol the area below the line is filled with and the points of this area, when it is
+    /// filled with a pattern
+    pattern: Option<(&'static str, Vec<(f64, f64)>)>,
+}
+
+/// The graph area of a chart along with the projections of the values of its horizontal and
+/// vertical axes to its columns and rows, built once per draw call
+#[derive(Debug, Clone, Copy)]
+struct Plane {
+    area: Rect,
+    x: Projection,
+    y: Projection,
 }
 
 /// Marker to use when plotting data points
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Marker {
     /// One point per cell
     Dot,
     /// Up to 8 points per cell
     Braille,
+    /// One point per cell drawn with the given symbol. Wide symbols span several cells, their
+    /// position being rounded so that they never overlap each other.
+    Custom(&'static str),
+}
+
+/// Describes how the points of a dataset are graphed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphType {
+    /// Draw each point
+    Scatter,
+    /// Draw each point and the lines joining consecutive points
+    Line,
+    /// Draw a line and fill the area between this line and the baseline of the dataset
+    Area,
+}
+
+/// Describes how the area of a dataset is filled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pattern {
+    /// The area is filled with the marker of the dataset
+    Solid,
+    /// The area is filled with dots
+    Dots,
+    /// The area is filled with diagonal lines
+    Hatch,
+    /// The area is filled with crossed diagonal lines
+    Cross,
+}
+
+impl Pattern {
+    /// Returns the symbol used to fill cells, if any
+    fn symbol(&self) -> Option<&'static str> {
+        match *self {
+            Pattern::Solid => None,
+            Pattern::Dots => Some(symbols::pattern::DOTS),
+            Pattern::Hatch => Some(symbols::pattern::HATCH),
+            Pattern::Cross => Some(symbols::pattern::CROSS),
+        }
+    }
+}
+
+/// Describes how the lines joining consecutive points are drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationMode {
+    /// Straight segments join the points
+    Linear,
+    /// A smooth Catmull-Rom spline goes through the points
+    CatmullRom,
 }
 
 /// A group of data points
@@ -93,10 +570,38 @@ pub struct Dataset<'a> {
     name: &'a str,
     /// A reference to the actual data
     data: &'a [(f64, f64)],
+    /// Points given with the error on their y value, drawn as error bars
+    error_data: &'a [(f64, f64, f64)],
+    /// Points given with a magnitude, drawn as bubbles
+    bubbles: &'a [(f64, f64, f64)],
+    /// Raw samples drawn as a histogram
+    samples: &'a [f64],
+    /// Number of bins of the histogram
+    bins: usize,
     /// Symbol used for each points of this dataset
     marker: Marker,
     /// Style used to plot this dataset
     style: Style,
+    /// Labels drawn next to some of the points, each one given with the index of its point
+    point_labels: &'a [(usize, &'a str)],
+    /// Datasets with a higher z-index are drawn above the others
+    z: i32,
+    /// How the points are graphed
+    graph_type: GraphType,
+    /// Width of the lines (in cells) when graphed as lines
+    line_width: u16,
+    /// Value from which the area is filled, defaults to the lower bound of the y axis
+    baseline: Option<f64>,
+    /// How the area is filled
+    fill_pattern: Pattern,
+    /// Whether the points lying outside of the bounds of the axes are drawn on their edge
+    clip_to_bounds: bool,
+    /// Tolerance (in data units) of the simplification of the lines
+    simplify: Option<f64>,
+    /// Whether the lines are drawn across the missing points
+    connect_gaps: bool,
+    /// How the lines join the points
+    interpolation: InterpolationMode,
 }
 
 impl<'a> Default for Dataset<'a> {
@@ -104,8 +609,22 @@ impl<'a> Default for Dataset<'a> {
         Dataset {
             name: "",
             data: &[],
+            error_data: &[],
+            bubbles: &[],
+            samples: &[],
+            bins: 0,
             marker: Marker::Dot,
             style: Style::default(),
+            point_labels: &[],
+            z: 0,
+            graph_type: GraphType::Scatter,
+            line_width: 1,
+            baseline: None,
+            fill_pattern: Pattern::Solid,
+            clip_to_bounds: false,
+            simplify: None,
+            connect_gaps: false,
+            interpolation: InterpolationMode::Linear,
         }
     }
 }
@@ -118,6 +637,43 @@ impl<'a> Dataset<'a> {
 
     pub fn data(mut self, data: &'a [(f64, f64)]) -> Dataset<'a> {
         self.data = data;
+        self.error_data = &[];
+        self.bubbles = &[];
+        self.samples = &[];
+        self
+    }
+
+    /// Set the data as `(x, y, y_err)` points. The `(x, y)` points are graphed as regular data
+    /// and a vertical error bar going from `y - y_err` to `y + y_err` is drawn behind each one.
+    pub fn error_data(mut self, data: &'a [(f64, f64, f64)]) -> Dataset<'a> {
+        self.error_data = data;
+        self.data = &[];
+        self.bubbles = &[];
+        self.samples = &[];
+        self
+    }
+
+    /// Set the data as `(x, y, size)` points drawn as a bubble chart: the marker of each point is
+    /// picked from `symbols::bubble::SIZES` according to its size relatively to the largest one
+    /// of the dataset, from the smallest glyph to the largest.
+    pub fn bubbles(mut self, data: &'a [(f64, f64, f64)]) -> Dataset<'a> {
+        self.bubbles = data;
+        self.data = &[];
+        self.error_data = &[];
+        self.samples = &[];
+        self
+    }
+
+    /// Draw the given samples as a histogram. The bounds of the x axis are divided in the given
+    /// number of bins of the same width and a bar as high as the number of samples falling in
+    /// each bin is drawn. When the bounds of the y axis are not set, the bars are scaled so that
+    /// the highest one fills the graph area.
+    pub fn histogram(mut self, samples: &'a [f64], bins: usize) -> Dataset<'a> {
+        self.samples = samples;
+        self.bins = bins;
+        self.data = &[];
+        self.error_data = &[];
+        self.bubbles = &[];
         self
     }
 
@@ -130,196 +686,1838 @@ impl<'a> Dataset<'a> {
         self.style = style;
         self
     }
-}
 
-/// A container that holds all the infos about where to display each elements of the chart (axis,
-/// labels, legend, ...).
-#[derive(Debug)]
-struct ChartLayout {
-    title_x: Option<(u16, u16)>,
-    title_y: Option<(u16, u16)>,
-    label_x: Option<u16>,
-    label_y: Option<u16>,
-    axis_x: Option<u16>,
-    axis_y: Option<u16>,
-    legend_area: Option<Rect>,
-    graph_area: Rect,
-}
+    /// Annotate some points of the dataset given their index in the data. Each label is drawn
+    /// on the right of its point if there is enough room for it in the graph area.
+    pub fn point_labels(mut self, labels: &'a [(usize, &'a str)]) -> Dataset<'a> {
+        self.point_labels = labels;
+        self
+    }
 
-impl Default for ChartLayout {
-    fn default() -> ChartLayout {
-        ChartLayout {
-            title_x: None,
-            title_y: None,
-            label_x: None,
-            label_y: None,
-            axis_x: None,
-            axis_y: None,
-            legend_area: None,
-            graph_area: Rect::default(),
-        }
+    /// Set the z-index of the dataset. Datasets are drawn from the lowest z-index to the highest
+    /// one, datasets sharing the same z-index being drawn in the order they were given to the
+    /// chart.
+    pub fn z(mut self, z: i32) -> Dataset<'a> {
+        self.z = z;
+        self
     }
-}
 
-/// A widget to plot one or more dataset in a cartesian coordinate system
-///
-/// # Examples
-///
-/// ```
-/// # extern crate tui;
-/// # use tui::widgets::{Block, Borders, Chart, Axis, Dataset, Marker};
-/// # use tui::style::{Style, Color};
-/// # fn main() {
-/// Chart::default()
-///     .block(Block::default().title("Chart"))
-///     .x_axis(Axis::default()
-///         .title("X Axis")
-///         .title_style(Style::default().fg(Color::Red))
-///         .style(Style::default().fg(Color::Gray))
-///         .bounds([0.0, 10.0])
-///         .labels(&["0.0", "5.0", "10.0"]))
-///     .y_axis(Axis::default()
-///         .title("Y Axis")
-///         .title_style(Style::default().fg(Color::Red))
-///         .style(Style::default().fg(Color::Gray))
-///         .bounds([0.0, 10.0])
-///         .labels(&["0.0", "5.0", "10.0"]))
-///     .datasets(&[Dataset::default()
-///                     .name("data1")
-///                     .marker(Marker::Dot)
-///                     .style(Style::default().fg(Color::Cyan))
-///                     .data(&[(0.0, 5.0), (1.0, 6.0), (1.5, 6.434)]),
-///                 Dataset::default()
-///                     .name("data2")
-///                     .marker(Marker::Braille)
-///                     .style(Style::default().fg(Color::Magenta))
-///                     .data(&[(4.0, 5.0), (5.0, 8.0), (7.66, 13.5)])]);
-/// # }
-pub struct Chart<'a, LX, LY>
-where
-    LX: AsRef<str> + 'a,
-    LY: AsRef<str> + 'a,
-{
-    /// A block to display around the widget eventually
-    block: Option<Block<'a>>,
-    /// The horizontal axis
-    x_axis: Axis<'a, LX>,
-    /// The vertical axis
-    y_axis: Axis<'a, LY>,
-    /// A reference to the datasets
-    datasets: &'a [Dataset<'a>],
-    /// The widget base style
-    style: Style,
-}
+    pub fn graph_type(mut self, graph_type: GraphType) -> Dataset<'a> {
+        self.graph_type = graph_type;
+        self
+    }
 
-impl<'a, LX, LY> Default for Chart<'a, LX, LY>
-where
-    LX: AsRef<str>,
-    LY: AsRef<str>,
-{
-    fn default() -> Chart<'a, LX, LY> {
-        Chart {
-            block: None,
-            x_axis: Axis::default(),
-            y_axis: Axis::default(),
-            style: Default::default(),
-            datasets: &[],
+    /// Set the width of the lines, either 1 or 2 cells. Thick lines are approximated by drawing
+    /// a second line next to the first one, below (resp. on the right of) mostly horizontal
+    /// (resp. vertical) lines, or on the other side on the edges of the graph area.
+    pub fn line_width(mut self, width: u16) -> Dataset<'a> {
+        self.line_width = width;
+        self
+    }
+
+    /// Set the value from which the area under the line is filled when graphed as an area. The
+    /// area is filled upward from the line when it goes below the baseline.
+    pub fn baseline(mut self, baseline: f64) -> Dataset<'a> {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Returns the points of the dataset, without their errors or sizes if any
+    fn points(&self) -> Cow<'a, [(f64, f64)]> {
+        if !self.error_data.is_empty() {
+            Cow::Owned(self.error_data.iter().map(|&(x, y, _)| (x, y)).collect())
+        } else if !self.bubbles.is_empty() {
+            Cow::Owned(self.bubbles.iter().map(|&(x, y, _)| (x, y)).collect())
+        } else {
+            Cow::Borrowed(self.data)
         }
     }
-}
 
-impl<'a, LX, LY> Chart<'a, LX, LY>
-where
-    LX: AsRef<str>,
-    LY: AsRef<str>,
-{
-    pub fn block(&'a mut self, block: Block<'a>) -> &mut Chart<'a, LX, LY> {
-        self.block = Some(block);
+    /// Returns the number of samples falling in each bin of the histogram spanning the given
+    /// bounds. The upper bound belongs to the last bin.
+    fn bin_counts(&self, bounds: [f64; 2]) -> Vec<u64> {
+        let mut counts = vec![0; self.bins];
+        let width = (bounds[1] - bounds[0]) / self.bins as f64;
+        for &sample in self.samples {
+            if self.bins == 0 || !(bounds[0]..=bounds[1]).contains(&sample) {
+                continue;
+            }
+            let bin = ((sample - bounds[0]) / width) as usize;
+            counts[bin.min(self.bins - 1)] += 1;
+        }
+        counts
+    }
+
+    /// Fill the area with a pattern rather than with the marker of the dataset so that the areas
+    /// of several datasets can be told apart without colors. The cells of the pattern lying
+    /// under a point of any of the datasets drawn before are left untouched.
+    pub fn fill_pattern(mut self, pattern: Pattern) -> Dataset<'a> {
+        self.fill_pattern = pattern;
         self
     }
 
-    pub fn style(&mut self, style: Style) -> &mut Chart<'a, LX, LY> {
-        self.style = style;
+    /// Draw the points lying outside of the bounds of the axes on the nearest edge of the graph
+    /// area, whatever the clip mode of the chart, e.g. for a series sampled over a wider range
+    /// than the one displayed. The lines joining the points are still clipped to the bounds.
+    pub fn clip_to_bounds(mut self, enabled: bool) -> Dataset<'a> {
+        self.clip_to_bounds = enabled;
         self
     }
 
-    pub fn x_axis(&mut self, axis: Axis<'a, LX>) -> &mut Chart<'a, LX, LY> {
-        self.x_axis = axis;
+    /// Simplify the lines before drawing them (with the Ramer-Douglas-Peucker algorithm) by
+    /// dropping the points lying closer than `epsilon`, in data units, to the line joining their
+    /// neighbours. This speeds up the plotting of dense data while keeping its shape.
+    pub fn simplify(mut self, epsilon: f64) -> Dataset<'a> {
+        self.simplify = Some(epsilon);
         self
     }
 
-    pub fn y_axis(&mut self, axis: Axis<'a, LY>) -> &mut Chart<'a, LX, LY> {
-        self.y_axis = axis;
+    /// Draw the lines across the points with a missing (NaN) coordinate, joining the points
+    /// around them, instead of leaving a break in the line
+    pub fn connect_gaps(mut self, connect: bool) -> Dataset<'a> {
+        self.connect_gaps = connect;
         self
     }
 
-    pub fn datasets(&mut self, datasets: &'a [Dataset<'a>]) -> &mut Chart<'a, LX, LY> {
-        self.datasets = datasets;
+    /// Draw the lines as smooth curves going through the points rather than as straight
+    /// segments. The curves are sampled once per cell before being plotted.
+    pub fn interpolation(mut self, interpolation: InterpolationMode) -> Dataset<'a> {
+        self.interpolation = interpolation;
         self
     }
 
-    /// Compute the internal layout of the chart given the area. If the area is too small some
-    /// elements may be automatically hidden
-    fn layout(&self, area: &Rect) -> ChartLayout {
+    /// Returns the points given with `data`
+    pub fn get_data(&self) -> &'a [(f64, f64)] {
+        self.data
+    }
+
+    /// Returns the color the dataset is plotted with
+    pub fn get_color(&self) -> Color {
+        self.style.fg
+    }
+}
+
+/// A line drawn across the whole graph area at a given value of one of the axes, e.g. to mark a
+/// threshold. Named lines are listed in the legend after the datasets.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::widgets::{Chart, ReferenceLine};
+/// # use tui::style::{Color, Style};
+/// # fn main() {
+/// Chart::<&str, &str>::default().reference_lines(&[
+///     ReferenceLine::hline(80.0)
+///         .label("limit")
+///         .style(Style::default().fg(Color::Red)),
+///     ReferenceLine::vline(0.0),
+/// ]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferenceLine<'a> {
+    /// Whether the line is drawn at a value of the y axis rather than of the x axis
+    horizontal: bool,
+    value: f64,
+    /// Name of the line in the legend
+    label: Option<&'a str>,
+    style: Style,
+}
+
+impl<'a> ReferenceLine<'a> {
+    /// A line joining the points whose y coordinate is the given value
+    pub fn hline(y: f64) -> ReferenceLine<'a> {
+        ReferenceLine {
+            horizontal: true,
+            value: y,
+            label: None,
+            style: Style::default(),
+        }
+    }
+
+    /// A line joining the points whose x coordinate is the given value
+    pub fn vline(x: f64) -> ReferenceLine<'a> {
+        ReferenceLine {
+            horizontal: false,
+            ..ReferenceLine::hline(x)
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> ReferenceLine<'a> {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> ReferenceLine<'a> {
+        self.style = style;
+        self
+    }
+
+    /// Returns the symbol used to draw the line, which depends on the axes being transposed
+    fn symbol(&self, transposed: bool) -> &'static str {
+        if self.horizontal != transposed {
+            symbols::line::HORIZONTAL
+        } else {
+            symbols::line::VERTICAL
+        }
+    }
+}
+
+/// A fixed size window over a stream of data points, the oldest points being dropped to make room
+/// for the new ones.
+///
+/// The points are stored twice in a contiguous block of memory so that the content of the window
+/// is always available as a slice, in chronological order, without any reallocation.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::widgets::{Dataset, RingDataset};
+/// # fn main() {
+/// let mut ring = RingDataset::new(2);
+/// ring.push((0.0, 1.0));
+/// ring.push((1.0, 2.0));
+/// ring.push((2.0, 3.0));
+/// assert_eq!(ring.as_slice(), &[(1.0, 2.0), (2.0, 3.0)]);
+/// Dataset::default().data(ring.as_slice());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingDataset {
+    points: Vec<(f64, f64)>,
+    capacity: usize,
+    start: usize,
+    len: usize,
+}
+
+impl RingDataset {
+    pub fn new(capacity: usize) -> RingDataset {
+        RingDataset {
+            points: vec![(0.0, 0.0); 2 * capacity],
+            capacity,
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds a point to the window, dropping the oldest one if the window is full
+    pub fn push(&mut self, point: (f64, f64)) {
+        if self.capacity == 0 {
+            return;
+        }
+        let end = (self.start + self.len) % self.capacity;
+        if self.len == self.capacity {
+            self.start = (self.start + 1) % self.capacity;
+        } else {
+            self.len += 1;
+        }
+        self.points[end] = point;
+        self.points[end + self.capacity] = point;
+    }
+
+    /// Returns the points of the window from the oldest to the most recent one
+    pub fn as_slice(&self) -> &[(f64, f64)] {
+        &self.points[self.start..self.start + self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Completes the given bounds so that they fit the given values, with a margin of 5% of the
+/// span. Missing bounds are set to the fixed one when there is no value.
+fn fit_bounds<I>(bounds: [Option<f64>; 2], values: I) -> [f64; 2]
+where
+    I: Iterator<Item = f64>,
+{
+    let (min, max) = values
+        .filter(|v| v.is_finite())
+        .fold((None, None), |(min, max): (Option<f64>, Option<f64>), v| {
+            (
+                Some(min.map_or(v, |m| m.min(v))),
+                Some(max.map_or(v, |m| m.max(v))),
+            )
+        });
+    let lower = bounds[0].or(min).or(bounds[1]).unwrap_or(0.0);
+    let upper = bounds[1].or(max).unwrap_or(lower);
+    let margin = (upper - lower).abs() * 0.05;
+    [
+        bounds[0].unwrap_or(lower - margin),
+        bounds[1].unwrap_or(upper + margin),
+    ]
+}
+
+/// Simplifies a polyline with the Ramer-Douglas-Peucker algorithm: the points lying closer than
+/// `epsilon` to the line joining the points kept around them are dropped.
+fn simplify(points: &[(f64, f64)], epsilon: f64) -> Vec<(f64, f64)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let (dx, dy) = (last.0 - first.0, last.1 - first.1);
+    let length = dx.hypot(dy);
+    let distance = |p: (f64, f64)| {
+        if length == 0.0 {
+            (p.0 - first.0).hypot(p.1 - first.1)
+        } else {
+            (dy * (p.0 - first.0) - dx * (p.1 - first.1)).abs() / length
+        }
+    };
+    let (farthest, max_distance) = points[1..points.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (i + 1, distance(p)))
+        .fold((0, 0.0), |acc, (i, d)| if d > acc.1 { (i, d) } else { acc });
+    if max_distance <= epsilon {
+        return vec![first, last];
+    }
+    let mut simplified = simplify(&points[..=farthest], epsilon);
+    simplified.pop();
+    simplified.extend(simplify(&points[farthest..], epsilon));
+    simplified
+}
+
+/// Samples the Catmull-Rom spline going through the given points, the curve between two
+/// consecutive points being sampled `steps` times (given the two points). The first and the last
+/// points are used as their own outer neighbours.
+fn catmull_rom<F>(points: &[(f64, f64)], steps: F) -> Vec<(f64, f64)>
+where
+    F: Fn((f64, f64), (f64, f64)) -> usize,
+{
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut curve = vec![points[0]];
+    for i in 0..points.len() - 1 {
+        let p0 = points[i.saturating_sub(1)];
+        let (p1, p2) = (points[i], points[i + 1]);
+        let p3 = points[(i + 2).min(points.len() - 1)];
+        let spline = |a: f64, b: f64, c: f64, d: f64, t: f64| {
+            0.5 * (2.0 * b + (c - a) * t + (2.0 * a - 5.0 * b + 4.0 * c - d) * t * t
+                + (3.0 * b - a - 3.0 * c + d) * t * t * t)
+        };
+        let n = steps(p1, p2).max(1);
+        for j in 1..n {
+            let t = j as f64 / n as f64;
+            curve.push((spline(p0.0, p1.0, p2.0, p3.0, t), spline(p0.1, p1.1, p2.1, p3.1, t)));
+        }
+        curve.push(p2);
+    }
+    curve
+}
+
+/// Only keeps one label every `step` labels, starting with the first one
+fn thin_out(labels: Vec<(u16, &str)>, step: usize) -> Vec<(u16, &str)> {
+    labels
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, _)| i % step == 0)
+        .map(|(_, label)| label)
+        .collect()
+}
+
+/// Only keeps the first label written on each row, the labels of the bounds always being kept
+fn distinct_rows(labels: Vec<(u16, &str)>) -> Vec<(u16, &str)> {
+    let last = labels.len().saturating_sub(1);
+    let mut rows = labels
+        .first()
+        .into_iter()
+        .chain(labels.last())
+        .map(|&(row, _)| row)
+        .collect::<Vec<u16>>();
+    labels
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, (row, _))| {
+            if i == 0 || i == last {
+                return true;
+            }
+            if rows.contains(&row) {
+                return false;
+            }
+            rows.push(row);
+            true
+        })
+        .map(|(_, label)| label)
+        .collect()
+}
+
+/// Returns the width of the column of the labels of the vertical axis, which also has to fit
+/// the first horizontal label since it is written on the left of the graph area
+fn labels_width(horizontal: &AxisView, vertical: &AxisView) -> u16 {
+    let mut max_width = vertical
+        .label_texts()
+        .iter()
+        .fold(0, |acc, l| max(l.width(), acc)) as u16;
+    if horizontal.label_rotation == Rotation::Horizontal {
+        if let Some(label) = horizontal.label_texts().first() {
+            max_width = max(max_width, label.width() as u16);
+        }
+    }
+    max_width
+}
+
+/// Returns the number of decimal places needed to tell apart values separated by the given gap.
+/// One more decimal place is used when the gap does not fall on the last one (e.g. 0.25).
+fn precision_for(gap: f64) -> usize {
+    if !gap.is_finite() || gap <= 0.0 {
+        return 0;
+    }
+    let precision = (-gap.log10()).ceil().clamp(0.0, 12.0) as i32;
+    let scaled = gap * 10f64.powi(precision);
+    if (scaled - scaled.round()).abs() > 1e-6 * scaled {
+        precision as usize + 1
+    } else {
+        precision as usize
+    }
+}
+
+/// Returns the offset of the i-th label from the start of an axis of the given length. The labels
+/// are evenly spread between both ends of the axis, a single label being placed at its start.
+fn label_offset(i: u16, labels_len: u16, length: u16) -> u16 {
+    if labels_len > 1 {
+        i * (length - 1) / (labels_len - 1)
+    } else {
+        0
+    }
+}
+
+/// Returns the color at the given ratio between two RGB colors, `None` if any of them is not an
+/// RGB color
+fn mix(from: Color, to: Color, t: f64) -> Option<Color> {
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let channel = |a: u8, b: u8| {
+                (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8
+            };
+            Some(Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2)))
+        }
+        _ => None,
+    }
+}
+
+/// A container that holds all the infos about where to display each elements of the chart (axis,
+/// labels, legend, ...).
+#[derive(Debug)]
+struct ChartLayout {
+    title_x: Option<(u16, u16)>,
+    title_y: Option<(u16, u16)>,
+    label_x: Option<u16>,
+    label_y: Option<u16>,
+    axis_x: Option<u16>,
+    axis_y: Option<u16>,
+    legend_area: Option<Rect>,
+    graph_area: Rect,
+}
+
+impl Default for ChartLayout {
+    fn default() -> ChartLayout {
+        ChartLayout {
+            title_x: None,
+            title_y: None,
+            label_x: None,
+            label_y: None,
+            axis_x: None,
+            axis_y: None,
+            legend_area: None,
+            graph_area: Rect::default(),
+        }
+    }
+}
+
+/// The elements of the chart left out of its layout to enlarge the graph area
+#[derive(Debug, Default)]
+struct Hidden {
+    horizontal_labels: bool,
+    vertical_labels: bool,
+    axes: bool,
+}
+
+/// A widget to plot one or more dataset in a cartesian coordinate system
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::widgets::{Block, Borders, Chart, Axis, Dataset, Marker};
+/// # use tui::style::{Style, Color};
+/// # fn main() {
+/// Chart::default()
+///     .block(Block::default().title("Chart"))
+///     .x_axis(Axis::default()
+///         .title("X Axis")
+///         .title_style(Style::default().fg(Color::Red))
+///         .style(Style::default().fg(Color::Gray))
+///         .bounds([0.0, 10.0])
+///         .labels(&["0.0", "5.0", "10.0"]))
+///     .y_axis(Axis::default()
+///         .title("Y Axis")
+///         .title_style(Style::default().fg(Color::Red))
+///         .style(Style::default().fg(Color::Gray))
+///         .bounds([0.0, 10.0])
+///         .labels(&["0.0", "5.0", "10.0"]))
+///     .datasets(&[Dataset::default()
+///                     .name("data1")
+///                     .marker(Marker::Dot)
+///                     .style(Style::default().fg(Color::Cyan))
+///                     .data(&[(0.0, 5.0), (1.0, 6.0), (1.5, 6.434)]),
+///                 Dataset::default()
+///                     .name("data2")
+///                     .marker(Marker::Braille)
+///                     .style(Style::default().fg(Color::Magenta))
+///                     .data(&[(4.0, 5.0), (5.0, 8.0), (7.66, 13.5)])]);
+/// # }
+pub struct Chart<'a, LX, LY>
+where
+    LX: AsRef<str> + 'a,
+    LY: AsRef<str> + 'a,
+{
+    /// A block to display around the widget eventually
+    block: Option<Block<'a>>,
+    /// The horizontal axis
+    x_axis: Axis<'a, LX>,
+    /// The vertical axis
+    y_axis: Axis<'a, LY>,
+    /// A reference to the datasets
+    datasets: &'a [Dataset<'a>],
+    /// The widget base style
+    style: Style,
+    /// Number of columns left blank on the right of the graph area
+    right_padding: u16,
+    /// Number of rows left blank at the top of the graph area
+    top_padding: u16,
+    /// Ratio between the width and the height of the graph area
+    letterbox: Option<f64>,
+    /// What happens to the points lying outside of the bounds of the axes
+    clip_mode: ClipMode,
+    /// Whether the intersections of the labels positions are marked in the graph area
+    dot_grid: bool,
+    /// Formats the coordinates of the hovered points
+    value_formatter: Option<Box<dyn Fn(f64, f64) -> String>>,
+    /// Whether the x axis is drawn vertically and the y axis horizontally
+    transposed: bool,
+    /// Text displayed in the graph area when there is nothing to plot
+    empty_message: &'a str,
+    /// The x axis bounds and the left margin shared with other charts
+    shared_x_axis: Option<SharedXAxis>,
+    /// Lines marking some values of the axes
+    reference_lines: &'a [ReferenceLine<'a>],
+    /// Colors of the background of the top and the bottom rows of the graph area
+    background_gradient: Option<(Color, Color)>,
+    /// Indices of two datasets and the colors of the background between them where the first
+    /// one is above and below the second one
+    fill_between: Option<(usize, usize, Color, Color)>,
+    /// Width and height below which the labels and the axes are hidden to enlarge the graph area
+    min_graph_area: (u16, u16),
+    /// Whether the whole area is used to plot the datasets, without axes, labels nor legend
+    compact: bool,
+    /// Number of blank columns and rows between the axes lines and their labels
+    label_gap: u16,
+}
+
+/// The bounds of an x axis and the space reserved on the left of the graph area, shared by
+/// several charts stacked vertically (e.g. prices above volumes) so that their columns line up.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Axis, Chart, SharedXAxis};
+/// # fn main() {
+/// let mut prices = Chart::default();
+/// prices
+///     .x_axis(Axis::default().labels(&["day 0", "day 30"]))
+///     .y_axis(Axis::default().bounds([0.0, 1000.0]).labels(&["0", "1000"]));
+/// let mut volumes: Chart<&str, &str> = Chart::default();
+/// volumes.y_axis(Axis::default().bounds([0.0, 5.0]).labels(&["0", "5"]));
+///
+/// let mut shared = SharedXAxis::new([0.0, 30.0]);
+/// shared.reserve(prices.required_left_margin());
+/// shared.reserve(volumes.required_left_margin());
+/// prices.shared_x_axis(shared);
+/// volumes.shared_x_axis(shared);
+///
+/// let prices_area = Rect::new(0, 0, 40, 10);
+/// let volumes_area = Rect::new(0, 10, 40, 5);
+/// assert_eq!(
+///     prices.project(0.0, 0.0, &prices_area).map(|(x, _)| x),
+///     volumes.project(0.0, 0.0, &volumes_area).map(|(x, _)| x)
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SharedXAxis {
+    bounds: [f64; 2],
+    left_margin: u16,
+}
+
+impl SharedXAxis {
+    pub fn new(bounds: [f64; 2]) -> SharedXAxis {
+        SharedXAxis {
+            bounds,
+            left_margin: 0,
+        }
+    }
+
+    /// Make sure that at least the given number of columns is reserved on the left of the graph
+    /// areas, typically the `required_left_margin` of each chart
+    pub fn reserve(&mut self, margin: u16) {
+        self.left_margin = max(self.left_margin, margin);
+    }
+
+    pub fn bounds(&self) -> [f64; 2] {
+        self.bounds
+    }
+
+    pub fn left_margin(&self) -> u16 {
+        self.left_margin
+    }
+}
+
+/// Describes what happens to the points lying outside of the bounds of the axes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClipMode {
+    /// The points are not drawn
+    Drop,
+    /// The points are drawn on the nearest edge of the graph area, e.g. to show that some values
+    /// are saturating
+    Clamp,
+}
+
+impl<'a, LX, LY> Default for Chart<'a, LX, LY>
+where
+    LX: AsRef<str>,
+    LY: AsRef<str>,
+{
+    fn default() -> Chart<'a, LX, LY> {
+        Chart {
+            block: None,
+            x_axis: Axis::default(),
+            y_axis: Axis::default(),
+            style: Default::default(),
+            datasets: &[],
+            right_padding: 0,
+            top_padding: 0,
+            letterbox: None,
+            clip_mode: ClipMode::Drop,
+            dot_grid: false,
+            value_formatter: None,
+            transposed: false,
+            empty_message: "No data",
+            shared_x_axis: None,
+            reference_lines: &[],
+            background_gradient: None,
+            fill_between: None,
+            compact: false,
+            label_gap: 0,
+            min_graph_area: (0, 0),
+        }
+    }
+}
+
+impl<'a, LX, LY> Chart<'a, LX, LY>
+where
+    LX: AsRef<str>,
+    LY: AsRef<str>,
+{
+    pub fn block(&'a mut self, block: Block<'a>) -> &mut Chart<'a, LX, LY> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(&mut self, style: Style) -> &mut Chart<'a, LX, LY> {
+        self.style = style;
+        self
+    }
+
+    pub fn x_axis(&mut self, axis: Axis<'a, LX>) -> &mut Chart<'a, LX, LY> {
+        self.x_axis = axis;
+        self.fit_bounds();
+        if let Some(shared) = self.shared_x_axis {
+            self.x_axis.bounds = shared.bounds;
+        }
+        self
+    }
+
+    pub fn y_axis(&mut self, axis: Axis<'a, LY>) -> &mut Chart<'a, LX, LY> {
+        self.y_axis = axis;
+        self.fit_bounds();
+        self
+    }
+
+    pub fn datasets(&mut self, datasets: &'a [Dataset<'a>]) -> &mut Chart<'a, LX, LY> {
+        self.datasets = datasets;
+        self.fit_bounds();
+        if let Some(shared) = self.shared_x_axis {
+            self.x_axis.bounds = shared.bounds;
+        }
+        self
+    }
+
+    /// Computes the bounds of the axes left to be computed from the datasets
+    fn fit_bounds(&mut self) {
+        let points = self.datasets.iter().flat_map(|d| d.points().into_owned());
+        if let Some(bounds) = self.x_axis.partial_bounds {
+            self.x_axis.bounds = fit_bounds(bounds, points.clone().map(|(x, _)| x));
+        }
+        if let Some(bounds) = self.y_axis.partial_bounds {
+            self.y_axis.bounds = fit_bounds(bounds, points.map(|(_, y)| y));
+        }
+    }
+
+    /// Draw lines at some values of the axes, below the datasets
+    pub fn reference_lines(&mut self, lines: &'a [ReferenceLine<'a>]) -> &mut Chart<'a, LX, LY> {
+        self.reference_lines = lines;
+        self
+    }
+
+    /// Fill the background of the graph area with colors fading from `top` on the first row to
+    /// `bottom` on the last one. Both colors have to be RGB colors, the background of the chart
+    /// is kept otherwise.
+    pub fn background_gradient(&mut self, top: Color, bottom: Color) -> &mut Chart<'a, LX, LY> {
+        self.background_gradient = Some((top, bottom));
+        self
+    }
+
+    /// Plot the datasets edge to edge in the whole area (inside the block), leaving out the axes,
+    /// their labels and titles and the legend, e.g. for tiny charts inlined in some text. Unlike
+    /// a sparkline, the chart keeps its bounds and can plot several datasets.
+    pub fn compact(&mut self, compact: bool) -> &mut Chart<'a, LX, LY> {
+        self.compact = compact;
+        self
+    }
+
+    /// Leave the given number of blank columns between the vertical axis and its labels, and of
+    /// blank rows between the horizontal axis and its labels (none by default)
+    pub fn label_gap(&mut self, gap: u16) -> &mut Chart<'a, LX, LY> {
+        self.label_gap = gap;
+        self
+    }
+
+    /// Fill the background between the lines joining the points of two datasets (given by their
+    /// indices) with `above` where the first one is higher than the second one and with `below`
+    /// elsewhere. The points of both datasets have to share the same x values.
+    pub fn fill_between(
+        &mut self,
+        a: usize,
+        b: usize,
+        above: Color,
+        below: Color,
+    ) -> &mut Chart<'a, LX, LY> {
+        self.fill_between = Some((a, b, above, below));
+        self
+    }
+
+    fn draw_fill_between(&self, plane: &Plane, buf: &mut Buffer) {
+        let graph_area = &plane.area;
+        let (a, b, above, below) = match self.fill_between {
+            Some(fill) => fill,
+            None => return,
+        };
+        let (a, b) = match (self.datasets.get(a), self.datasets.get(b)) {
+            (Some(a), Some(b)) => (a.data, b.data),
+            _ => return,
+        };
+        let [lower, upper] = self.y_axis.bounds;
+        let (x_cells, _) = self.cells(graph_area);
+        let x = Projection::new(self.x_axis.bounds, 0, x_cells).scale(self.x_axis.scale);
+        let interpolate = |p: (f64, f64), q: (f64, f64), value: f64| {
+            if q.0 == p.0 {
+                p.1
+            } else {
+                p.1 + (q.1 - p.1) * (value - p.0) / (q.0 - p.0)
+            }
+        };
+        for i in 0..x_cells {
+            let value = x.inverse(f32::from(i));
+            let segment = a.windows(2)
+                .zip(b.windows(2))
+                .find(|&(sa, _)| sa[0].0 <= value && value <= sa[1].0);
+            let (ya, yb) = match segment {
+                Some((sa, sb)) => (
+                    interpolate(sa[0], sa[1], value),
+                    interpolate(sb[0], sb[1], value),
+                ),
+                None => continue,
+            };
+            if ya == yb {
+                continue;
+            }
+            let color = if ya > yb { above } else { below };
+            let ends = (
+                self.project_in(value, ya.max(lower).min(upper), plane),
+                self.project_in(value, yb.max(lower).min(upper), plane),
+            );
+            if let (Some((x1, y1)), Some((x2, y2))) = ends {
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        buf.get_mut(x, y).set_bg(color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Set the size the graph area should at least have. When the chart is drawn in an area too
+    /// small for that, the labels of the vertical axis and then those of the horizontal one are
+    /// hidden, and the lines of the axes as a last resort, to make room for the plot.
+    pub fn min_graph_area(&mut self, width: u16, height: u16) -> &mut Chart<'a, LX, LY> {
+        self.min_graph_area = (width, height);
+        self
+    }
+
+    /// Returns the entries of the legend: the names of the datasets and of the reference lines,
+    /// the latter following a sample of the line
+    fn legend_entries(&self) -> Vec<(Option<&'static str>, &str, Style)> {
+        let datasets = self.datasets.iter().map(|d| (None, d.name, d.style));
+        let lines = self.reference_lines.iter().filter_map(|line| {
+            line.label
+                .map(|label| (Some(line.symbol(self.transposed)), label, line.style))
+        });
+        datasets.chain(lines).collect()
+    }
+
+    /// Draws a reference line across the graph area if its value lies within the bounds
+    fn draw_reference_line(&self, line: &ReferenceLine, plane: &Plane, buf: &mut Buffer) {
+        let (bounds, other) = if line.horizontal {
+            (self.y_axis.bounds, self.x_axis.bounds)
+        } else {
+            (self.x_axis.bounds, self.y_axis.bounds)
+        };
+        if line.value < bounds[0] || line.value > bounds[1] {
+            return;
+        }
+        let project = |v| {
+            if line.horizontal {
+                self.project_in(v, line.value, plane)
+            } else {
+                self.project_in(line.value, v, plane)
+            }
+        };
+        if let (Some(start), Some(end)) = (project(other[0]), project(other[1])) {
+            let symbol = line.symbol(self.transposed);
+            for x in start.0.min(end.0)..=start.0.max(end.0) {
+                for y in start.1.min(end.1)..=start.1.max(end.1) {
+                    buf.get_mut(x, y).set_symbol(symbol).set_style(line.style);
+                }
+            }
+        }
+    }
+
+    /// Reserve some blank columns on the right of the graph area. This gives some room to the
+    /// points lying on the upper bound of the x axis, which would otherwise be drawn over the
+    /// border of the chart or hidden below the legend.
+    pub fn right_padding(&mut self, padding: u16) -> &mut Chart<'a, LX, LY> {
+        self.right_padding = padding;
+        self
+    }
+
+    /// Reserve some blank rows at the top of the graph area (see `right_padding`)
+    pub fn top_padding(&mut self, padding: u16) -> &mut Chart<'a, LX, LY> {
+        self.top_padding = padding;
+        self
+    }
+
+    /// Keep the ratio between the width and the height (in cells) of the graph area to the given
+    /// value so that the shape of the plot does not depend on the area the chart is drawn in. The
+    /// chart is centered in its area, the margins being filled with the chart background.
+    pub fn letterbox(&mut self, ratio: f64) -> &mut Chart<'a, LX, LY> {
+        self.letterbox = Some(ratio);
+        self
+    }
+
+    /// Set what happens to the points lying outside of the bounds of the axes (they are dropped
+    /// by default). Only the points themselves are affected, the lines joining them are always
+    /// clipped to the bounds.
+    pub fn clip_mode(&mut self, mode: ClipMode) -> &mut Chart<'a, LX, LY> {
+        self.clip_mode = mode;
+        self
+    }
+
+    /// Mark each intersection of the positions of the x and y labels with a dim dot in the graph
+    /// area, a lighter alternative to drawing full gridlines
+    pub fn dot_grid(&mut self, visible: bool) -> &mut Chart<'a, LX, LY> {
+        self.dot_grid = visible;
+        self
+    }
+
+    /// Swap the roles of the axes: the x axis is drawn vertically on the left of the graph area,
+    /// with its values growing upward, and the y axis horizontally below it. The labels and the
+    /// title of each axis follow it.
+    pub fn transposed(&mut self, transposed: bool) -> &mut Chart<'a, LX, LY> {
+        self.transposed = transposed;
+        self
+    }
+
+    /// Set the text centered in the graph area when none of the datasets has a point within the
+    /// bounds of the axes ("No data" by default). Nothing is displayed if the text is empty.
+    pub fn empty_message(&mut self, message: &'a str) -> &mut Chart<'a, LX, LY> {
+        self.empty_message = message;
+        self
+    }
+
+    /// Use the bounds of the shared axis for the x axis, whatever those given to `x_axis`, and
+    /// reserve its left margin so that the graph area starts at the same column as the ones of
+    /// the other charts sharing it
+    pub fn shared_x_axis(&mut self, shared: SharedXAxis) -> &mut Chart<'a, LX, LY> {
+        self.x_axis.bounds = shared.bounds;
+        self.shared_x_axis = Some(shared);
+        self
+    }
+
+    /// Returns the number of columns reserved on the left of the graph area by the shared axis
+    fn shared_left_margin(&self) -> u16 {
+        self.shared_x_axis.map_or(0, |shared| shared.left_margin)
+    }
+
+    /// Returns whether none of the datasets has a point to plot in the given graph area
+    fn is_empty(&self, plane: &Plane) -> bool {
+        self.datasets.iter().all(|dataset| {
+            dataset.samples.is_empty()
+                && dataset
+                    .points()
+                    .iter()
+                    .all(|&(x, y)| self.project_in(x, y, plane).is_none())
+        })
+    }
+
+    /// Returns the axes drawn horizontally and vertically
+    fn axes<'b>(&'b self) -> (AxisView<'b>, AxisView<'b>) {
+        if self.transposed {
+            (self.y_axis.view(), self.x_axis.view())
+        } else {
+            (self.x_axis.view(), self.y_axis.view())
+        }
+    }
+
+    /// Returns the number of cells of the given graph area along the x and the y axes
+    fn cells(&self, graph_area: &Rect) -> (u16, u16) {
+        if self.transposed {
+            (graph_area.height, graph_area.width)
+        } else {
+            (graph_area.width, graph_area.height)
+        }
+    }
+
+    /// Set how the coordinates of the hovered points are displayed by `hover_tooltip`. They are
+    /// formatted as `(x, y)` with two decimals by default.
+    pub fn value_formatter(
+        &mut self,
+        formatter: Box<dyn Fn(f64, f64) -> String>,
+    ) -> &mut Chart<'a, LX, LY> {
+        self.value_formatter = Some(formatter);
+        self
+    }
+
+    /// Returns the cell a data point is plotted to when the chart is drawn in the given area, or
+    /// `None` if the point lies outside of the axes bounds or if there is no room left to plot
+    /// anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::widgets::{Axis, Chart};
+    /// # use tui::layout::Rect;
+    /// # fn main() {
+    /// let mut chart: Chart<&str, &str> = Chart::default();
+    /// chart
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// assert_eq!(chart.project(0.0, 0.0, &area), Some((0, 10)));
+    /// assert_eq!(chart.project(5.0, 5.0, &area), Some((5, 5)));
+    /// assert_eq!(chart.project(11.0, 5.0, &area), None);
+    /// # }
+    /// ```
+    pub fn project(&self, x: f64, y: f64, area: &Rect) -> Option<(u16, u16)> {
+        let graph_area = self.layout(&self.chart_area(area)).graph_area;
+        self.project_in(x, y, &self.plane(&graph_area))
+    }
+
+    /// Returns the index of the dataset and the index of the point plotted the closest to the
+    /// given cell when the chart is drawn in the given area. Only the points up to one cell away
+    /// are taken into account. When several points are as close, the one drawn on top wins.
+    pub fn point_at(&self, x: u16, y: u16, area: &Rect) -> Option<(usize, usize)> {
+        let graph_area = self.layout(&self.chart_area(area)).graph_area;
+        let plane = self.plane(&graph_area);
+        let mut datasets = self.datasets.iter().enumerate().collect::<Vec<_>>();
+        datasets.sort_by_key(|&(_, d)| d.z);
+        let mut closest = None;
+        let mut min_distance = 2;
+        for (i, dataset) in datasets {
+            for (j, &(px, py)) in dataset.points().iter().enumerate() {
+                if let Some((cx, cy)) = self.project_in(px, py, &plane) {
+                    let dx = i64::from(cx) - i64::from(x);
+                    let dy = i64::from(cy) - i64::from(y);
+                    let distance = dx * dx + dy * dy;
+                    if distance <= min_distance {
+                        min_distance = distance;
+                        closest = Some((i, j));
+                    }
+                }
+            }
+        }
+        closest
+    }
+
+    /// Draws a framed text box next to the given cell, e.g. to show the value of the point under
+    /// the mouse cursor, once the chart has been drawn in the given area. The box is drawn on the
+    /// right of the cell when there is enough room for it in the graph area and on its left
+    /// otherwise. Nothing is drawn if the box does not fit in the graph area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::buffer::Buffer;
+    /// # use tui::widgets::{Axis, Chart, Dataset, Widget};
+    /// # use tui::layout::Rect;
+    /// # fn main() {
+    /// let area = Rect::new(0, 0, 20, 10);
+    /// let mut buf = Buffer::empty(area);
+    /// let data = [(2.0, 5.0)];
+    /// let datasets = [Dataset::default().data(&data)];
+    /// let mut chart = Chart::<&str, &str>::default();
+    /// chart
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .datasets(&datasets)
+    ///     .draw(&area, &mut buf);
+    /// let (x, y) = chart.project(2.0, 5.0, &area).unwrap();
+    /// if let Some((i, j)) = chart.point_at(x, y, &area) {
+    ///     let (value_x, value_y) = data[j];
+    ///     chart.tooltip(x, y, &format!("{}: ({}, {})", i, value_x, value_y), &area, &mut buf);
+    /// }
+    /// # }
+    /// ```
+    pub fn tooltip(&self, x: u16, y: u16, text: &str, area: &Rect, buf: &mut Buffer) {
+        let graph_area = self.layout(&self.chart_area(area)).graph_area;
+        let width = text.width() as u16 + 2;
+        let height = 3;
+        if width > graph_area.width || height > graph_area.height {
+            return;
+        }
+        let left = if x + 1 + width <= graph_area.right() {
+            x + 1
+        } else {
+            x.saturating_sub(width).max(graph_area.left())
+        };
+        let top = y
+            .saturating_sub(1)
+            .max(graph_area.top())
+            .min(graph_area.bottom() - height);
+        let tooltip_area = Rect::new(left, top, width, height);
+        buf.draw_box(&tooltip_area, &self.style, &self.style, Some(BorderType::Plain));
+        buf.set_string(left + 1, top + 1, text, &self.style);
+    }
+
+    /// Draws a tooltip (see `tooltip`) showing the coordinates of the point plotted the closest
+    /// to the given cell (see `point_at`), formatted by the value formatter of the chart. Returns
+    /// the index of the dataset and the index of the point if one was found.
+    pub fn hover_tooltip(
+        &self,
+        x: u16,
+        y: u16,
+        area: &Rect,
+        buf: &mut Buffer,
+    ) -> Option<(usize, usize)> {
+        let (i, j) = self.point_at(x, y, area)?;
+        let (px, py) = self.datasets[i].points()[j];
+        let text = match self.value_formatter {
+            Some(ref formatter) => formatter(px, py),
+            None => format!("({:.2}, {:.2})", px, py),
+        };
+        let graph_area = self.layout(&self.chart_area(area)).graph_area;
+        if let Some((cx, cy)) = self.project_in(px, py, &self.plane(&graph_area)) {
+            self.tooltip(cx, cy, &text, area, buf);
+        }
+        Some((i, j))
+    }
+
+    /// Returns the bounds of the x and the y axes covering the given selection of cells (e.g.
+    /// dragged with the mouse) when the chart is drawn in the given area, to zoom on it by giving
+    /// them to the axes for the next frame. The selection is restricted to the graph area and the
+    /// current bounds are returned if it lies outside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::widgets::{Axis, Chart};
+    /// # use tui::layout::Rect;
+    /// # fn main() {
+    /// let mut chart: Chart<&str, &str> = Chart::default();
+    /// chart
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// let (x_bounds, y_bounds) = chart.bounds_for_selection(&Rect::new(2, 0, 3, 6), &area);
+    /// assert_eq!(x_bounds, [2.0, 4.0]);
+    /// assert_eq!(y_bounds, [5.0, 10.0]);
+    /// # }
+    /// ```
+    pub fn bounds_for_selection(&self, selection: &Rect, area: &Rect) -> ([f64; 2], [f64; 2]) {
+        let graph_area = self.layout(&self.chart_area(area)).graph_area;
+        let selection = selection.intersection(&graph_area);
+        if selection.width < 1 || selection.height < 1 {
+            return (self.x_axis.bounds, self.y_axis.bounds);
+        }
+        let Plane { x, y, .. } = self.plane(&graph_area);
+        let horizontal = [
+            x.inverse(f32::from(selection.left())),
+            x.inverse(f32::from(selection.right() - 1)),
+        ];
+        let vertical = [
+            y.inverse(f32::from(selection.bottom() - 1)),
+            y.inverse(f32::from(selection.top())),
+        ];
+        if self.transposed {
+            (vertical, horizontal)
+        } else {
+            (horizontal, vertical)
+        }
+    }
+
+    /// Moves a data point lying outside of the bounds of the axes to their nearest edge
+    fn clamp(&self, px: f64, py: f64) -> (f64, f64) {
+        (
+            px.max(self.x_axis.bounds[0]).min(self.x_axis.bounds[1]),
+            py.max(self.y_axis.bounds[0]).min(self.y_axis.bounds[1]),
+        )
+    }
+
+    /// Maps a data point to a cell of the given graph area.
+    fn project_in(&self, px: f64, py: f64, plane: &Plane) -> Option<(u16, u16)> {
+        let area = plane.area;
+        if area.width < 1 || area.height < 1 || !px.is_finite() || !py.is_finite() {
+            return None;
+        }
+        let (px, py) = match self.clip_mode {
+            ClipMode::Drop => {
+                if px < self.x_axis.bounds[0] || px > self.x_axis.bounds[1]
+                    || py < self.y_axis.bounds[0] || py > self.y_axis.bounds[1]
+                {
+                    return None;
+                }
+                (px, py)
+            }
+            ClipMode::Clamp => self.clamp(px, py),
+        };
+        let (px, py) = if self.transposed { (py, px) } else { (px, py) };
+        Some((plane.x.forward(px) as u16, plane.y.forward(py) as u16))
+    }
+
+    /// Returns the bounds and the scales of the axes drawn horizontally and vertically
+    fn scales(&self) -> (([f64; 2], Scale), ([f64; 2], Scale)) {
+        let x = (self.x_axis.bounds, self.x_axis.scale);
+        let y = (self.y_axis.bounds, self.y_axis.scale);
+        if self.transposed {
+            (y, x)
+        } else {
+            (x, y)
+        }
+    }
+
+    /// Returns the projections of the values of the axes to the cells of the given graph area
+    fn plane(&self, graph_area: &Rect) -> Plane {
+        let ((x_bounds, x_scale), (y_bounds, y_scale)) = self.scales();
+        Plane {
+            area: *graph_area,
+            x: Projection::new(x_bounds, graph_area.left(), graph_area.width).scale(x_scale),
+            y: Projection::new(y_bounds, graph_area.top(), graph_area.height)
+                .scale(y_scale)
+                .reversed(true),
+        }
+    }
+
+    /// Returns the Catmull-Rom spline going through the points of a dataset, sampled once per
+    /// cell. The points with a missing (NaN) coordinate still break the curve unless the gaps are
+    /// connected.
+    fn spline(
+        &self,
+        data: &[(f64, f64)],
+        connect_gaps: bool,
+        plane: &Plane,
+    ) -> Vec<(f64, f64)> {
+        let is_missing = |&(x, y): &(f64, f64)| !x.is_finite() || !y.is_finite();
+        let points = if connect_gaps {
+            data.iter().cloned().filter(|p| !is_missing(p)).collect()
+        } else {
+            data.to_vec()
+        };
+        let (x, y) = (plane.x, plane.y);
+        let steps = |p: (f64, f64), q: (f64, f64)| {
+            let (p, q) = if self.transposed {
+                ((p.1, p.0), (q.1, q.0))
+            } else {
+                (p, q)
+            };
+            let columns = (x.forward(q.0) - x.forward(p.0)).abs();
+            let rows = (y.forward(q.1) - y.forward(p.1)).abs();
+            let steps = columns.max(rows).ceil();
+            if steps.is_finite() {
+                steps.min(f32::from(u16::MAX)) as usize
+            } else {
+                1
+            }
+        };
+        let mut curve = Vec::with_capacity(points.len());
+        for run in points.split(is_missing).filter(|run| !run.is_empty()) {
+            if !curve.is_empty() {
+                curve.push((f64::NAN, f64::NAN));
+            }
+            curve.extend(catmull_rom(run, steps));
+        }
+        curve
+    }
+
+    /// Returns the segments joining the consecutive points of a dataset, clipped to the bounds of
+    /// the axes. The points with a missing (NaN) coordinate break the line unless the gaps are
+    /// connected, in which case they are skipped.
+    fn segments(&self, data: &[(f64, f64)], connect_gaps: bool) -> Vec<Segment> {
+        let is_finite = |&(x, y): &(f64, f64)| x.is_finite() && y.is_finite();
+        if connect_gaps {
+            let points = data.iter().cloned().filter(is_finite).collect::<Vec<(f64, f64)>>();
+            return self.segments(&points, false);
+        }
+        data.windows(2)
+            .filter(|pair| is_finite(&pair[0]) && is_finite(&pair[1]))
+            .filter_map(|pair| self.clip(pair[0], pair[1]))
+            .collect()
+    }
+
+    /// Clips a segment to the bounds of the axes (Liang-Barsky algorithm)
+    fn clip(&self, p1: (f64, f64), p2: (f64, f64)) -> Option<Segment> {
+        let (x1, y1) = p1;
+        let (dx, dy) = (p2.0 - x1, p2.1 - y1);
+        let (mut t0, mut t1) = (0.0f64, 1.0f64);
+        for &(p, q) in &[
+            (-dx, x1 - self.x_axis.bounds[0]),
+            (dx, self.x_axis.bounds[1] - x1),
+            (-dy, y1 - self.y_axis.bounds[0]),
+            (dy, self.y_axis.bounds[1] - y1),
+        ] {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+            } else {
+                let r = q / p;
+                if p < 0.0 {
+                    t0 = t0.max(r);
+                } else {
+                    t1 = t1.min(r);
+                }
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+        Some(((x1 + t0 * dx, y1 + t0 * dy), (x1 + t1 * dx, y1 + t1 * dy)))
+    }
+
+    /// Returns the position in cells of the given point, given in data units
+    fn on_screen(&self, (x, y): (f64, f64), plane: &Plane) -> (f64, f64) {
+        let (x, y) = if self.transposed { (y, x) } else { (x, y) };
+        (f64::from(plane.x.forward(x)), f64::from(plane.y.forward(y)))
+    }
+
+    /// Returns points regularly spread on screen along a segment, close enough to each other to
+    /// get a continuous line once drawn with braille patterns in the given area, whatever the
+    /// scales of the axes.
+    fn sample(&self, segment: Segment, plane: &Plane) -> Vec<(f64, f64)> {
+        let (p, q) = segment;
+        let (c1, r1) = self.on_screen(p, plane);
+        let (c2, r2) = self.on_screen(q, plane);
+        // Braille patterns have 2 dots per column and 4 per row
+        let steps = ((c2 - c1).abs() * 2.0).max((r2 - r1).abs() * 4.0).ceil();
+        let steps = if steps.is_finite() && steps >= 1.0 {
+            steps.min(f64::from(u16::MAX)) as usize
+        } else {
+            1
+        };
+        (0..steps + 1)
+            .map(|i| {
+                // The ends are kept as they are to be clipped like the points of the dataset
+                if i == 0 {
+                    return p;
+                } else if i == steps {
+                    return q;
+                }
+                let t = i as f64 / steps as f64;
+                let (p, q) = if self.transposed {
+                    ((p.1, p.0), (q.1, q.0))
+                } else {
+                    (p, q)
+                };
+                // Along an axis where the segment does not move on screen, e.g. in a graph area a
+                // single cell thick, the values cannot be told apart from their cells
+                let x = if c1 == c2 {
+                    p.0 + (q.0 - p.0) * t
+                } else {
+                    plane.x.inverse((c1 + (c2 - c1) * t) as f32)
+                };
+                let y = if r1 == r2 {
+                    p.1 + (q.1 - p.1) * t
+                } else {
+                    plane.y.inverse((r1 + (r2 - r1) * t) as f32)
+                };
+                if self.transposed {
+                    (y, x)
+                } else {
+                    (x, y)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns whether a segment is closer to an horizontal line than to a vertical one once
+    /// drawn in the given area.
+    fn is_horizontal(&self, (p, q): Segment, plane: &Plane) -> bool {
+        let (c1, r1) = self.on_screen(p, plane);
+        let (c2, r2) = self.on_screen(q, plane);
+        (c2 - c1).abs() >= (r2 - r1).abs()
+    }
+
+    /// Returns the points filling the area between the given segments of a dataset and its
+    /// baseline
+    fn fill(&self, dataset: &Dataset, segments: &[Segment], plane: &Plane) -> Vec<(f64, f64)> {
+        let baseline = dataset
+            .baseline
+            .unwrap_or(self.y_axis.bounds[0])
+            .max(self.y_axis.bounds[0])
+            .min(self.y_axis.bounds[1]);
+        let mut fill = Vec::new();
+        for &segment in segments {
+            for (x, y) in self.sample(segment, plane) {
+                fill.extend(self.sample(((x, y), (x, baseline)), plane));
+            }
+        }
+        fill
+    }
+
+    /// Draws a line clipped to the graph area across the error of each point of a dataset
+    fn draw_error_bars(&self, dataset: &Dataset, plane: &Plane, buf: &mut Buffer) {
+        let [lower, upper] = self.y_axis.bounds;
+        for &(x, y, error) in dataset.error_data {
+            let (low, high) = (y - error.abs(), y + error.abs());
+            if high < lower || low > upper {
+                continue;
+            }
+            let top = self.project_in(x, high.min(upper), plane);
+            let bottom = self.project_in(x, low.max(lower), plane);
+            let symbol = if self.transposed {
+                symbols::line::HORIZONTAL
+            } else {
+                symbols::line::VERTICAL
+            };
+            if let (Some((x1, y1)), Some((x2, y2))) = (top, bottom) {
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        buf.get_mut(x, y)
+                            .set_symbol(symbol)
+                            .set_fg(dataset.style.fg)
+                            .set_bg(dataset.style.bg);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draws the samples of a dataset as bars rising from the bottom of the graph area, or from
+    /// its left side when the chart is transposed
+    fn plot_histogram(&self, dataset: &Dataset, plane: &Plane, buf: &mut Buffer) {
+        let graph_area = &plane.area;
+        let counts = dataset.bin_counts(self.x_axis.bounds);
+        let bounds = if self.y_axis.bounds == [0.0, 0.0] {
+            [0.0, counts.iter().cloned().max().unwrap_or(0) as f64]
+        } else {
+            self.y_axis.bounds
+        };
+        if counts.is_empty() || bounds[1] <= bounds[0] {
+            return;
+        }
+        let (x_cells, y_cells) = self.cells(graph_area);
+        let x = Projection::new(self.x_axis.bounds, 0, x_cells).reversed(self.transposed);
+        let bin_width = (self.x_axis.bounds[1] - self.x_axis.bounds[0]) / counts.len() as f64;
+        for i in 0..x_cells {
+            let value = x.inverse(f32::from(i)) - self.x_axis.bounds[0];
+            let bin = ((value / bin_width).max(0.0) as usize).min(counts.len() - 1);
+            let t = (counts[bin] as f64 - bounds[0]) / (bounds[1] - bounds[0]);
+            let height = (t.clamp(0.0, 1.0) * f64::from(y_cells)).round() as u16;
+            for j in 0..height {
+                let (column, row) = if self.transposed {
+                    (graph_area.left() + j, graph_area.top() + i)
+                } else {
+                    (graph_area.left() + i, graph_area.bottom() - 1 - j)
+                };
+                buf.get_mut(column, row)
+                    .set_symbol(symbols::block::FULL)
+                    .set_fg(dataset.style.fg)
+                    .set_bg(dataset.style.bg);
+            }
+        }
+    }
+
+    fn plot_bubbles(&self, dataset: &Dataset, plane: &Plane, buf: &mut Buffer) {
+        let sizes = &symbols::bubble::SIZES;
+        let largest = dataset
+            .bubbles
+            .iter()
+            .fold(0.0, |acc: f64, &(_, _, size)| acc.max(size));
+        for &(x, y, size) in dataset.bubbles {
+            if let Some((x, y)) = self.project_in(x, y, plane) {
+                let step = if largest > 0.0 && size > 0.0 {
+                    ((size / largest * sizes.len() as f64).ceil() as usize).clamp(1, sizes.len())
+                } else {
+                    1
+                };
+                buf.get_mut(x, y)
+                    .set_symbol(sizes[step - 1])
+                    .set_fg(dataset.style.fg)
+                    .set_bg(dataset.style.bg);
+            }
+        }
+    }
+
+    /// Returns the shapes the given dataset is plotted with
+    fn shapes(&self, dataset: &Dataset, plane: &Plane) -> Shapes {
+        let simplified = match (dataset.simplify, dataset.graph_type) {
+            (Some(epsilon), GraphType::Line) | (Some(epsilon), GraphType::Area) => {
+                Some(simplify(dataset.data, epsilon))
+            }
+            _ => None,
+        };
+        let data = simplified.as_ref().map_or(dataset.data, |data| &data[..]);
+        let segments = match (dataset.graph_type, dataset.interpolation) {
+            (GraphType::Scatter, _) => Vec::new(),
+            (_, InterpolationMode::Linear) => self.segments(data, dataset.connect_gaps),
+            (_, InterpolationMode::CatmullRom) => {
+                let curve = self.spline(data, dataset.connect_gaps, plane);
+                self.segments(&curve, false)
+            }
+        };
+        let mut fill = match dataset.graph_type {
+            GraphType::Area => self.fill(dataset, &segments, plane),
+            _ => Vec::new(),
+        };
+        // Patterns are drawn once the line has been drawn, instead of being part of it
+        let pattern = dataset
+            .fill_pattern
+            .symbol()
+            .map(|symbol| (symbol, mem::take(&mut fill)));
+        let points = if dataset.clip_to_bounds {
+            data.iter()
+                .map(|&(x, y)| self.clamp(x, y))
+                .collect::<Vec<(f64, f64)>>()
+        } else {
+            data.to_vec()
+        };
+        Shapes {
+            points,
+            segments,
+            fill,
+            pattern,
+        }
+    }
+
+    /// Returns the points painted on the canvas of a dataset plotted with braille markers, as
+    /// positions in cells from the bottom left corner of the graph area
+    fn braille_points(&self, dataset: &Dataset, shapes: &Shapes, plane: &Plane) -> Vec<(f64, f64)> {
+        let area = plane.area;
+        let right = f64::from(area.width.saturating_sub(1));
+        let top = f64::from(area.height.saturating_sub(1));
+        let project = |&(x, y): &(f64, f64)| {
+            let (x, y) = if self.transposed { (y, x) } else { (x, y) };
+            (
+                f64::from(plane.x.forward(x)) - f64::from(area.left()),
+                top - (f64::from(plane.y.forward(y)) - f64::from(area.top())),
+            )
+        };
+        let mut coords = shapes.points.iter().map(project).collect::<Vec<(f64, f64)>>();
+        for &segment in &shapes.segments {
+            let points = self.sample(segment, plane)
+                .iter()
+                .map(project)
+                .collect::<Vec<(f64, f64)>>();
+            if dataset.line_width > 1 {
+                // One row below or one column on the right, or on the other side on the edges of
+                // the graph area
+                let horizontal = self.is_horizontal(segment, plane);
+                coords.extend(points.iter().map(|&(x, y)| {
+                    if horizontal {
+                        (x, if (0.0..1.0).contains(&y) { y + 1.0 } else { y - 1.0 })
+                    } else if x > right - 1.0 && x <= right {
+                        (x - 1.0, y)
+                    } else {
+                        (x + 1.0, y)
+                    }
+                }));
+            }
+            coords.extend(points);
+        }
+        coords.extend(shapes.fill.iter().map(project));
+        coords
+    }
+
+    /// Returns the canvas painting the given points, given as positions in cells from the bottom
+    /// left corner of the graph area, with braille markers of the given color
+    fn braille_canvas<'c>(
+        &self,
+        coords: &'c [(f64, f64)],
+        color: Color,
+        plane: &Plane,
+    ) -> Canvas<'c, impl Fn(&mut Context) + 'c> {
+        let mut canvas = Canvas::default();
+        canvas
+            .background_color(self.style.bg)
+            .x_bounds([0.0, f64::from(plane.area.width.saturating_sub(1))])
+            .y_bounds([0.0, f64::from(plane.area.height.saturating_sub(1))])
+            .paint(move |ctx| {
+                ctx.draw(&Points { coords, color });
+            });
+        canvas
+    }
+
+    /// Returns the braille dots set by the datasets plotted with braille markers in each cell of
+    /// the graph area, row by row, before they are turned into braille patterns, e.g. to check
+    /// precisely where their points were put. Each cell is given as the set of its dots (see
+    /// `canvas::DOTS` for the bit of each dot).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate tui;
+    /// # use tui::layout::Rect;
+    /// # use tui::widgets::canvas::DOTS;
+    /// # use tui::widgets::{Axis, Chart, Dataset, Marker};
+    /// # fn main() {
+    /// let data = [(0.0, 1.0)];
+    /// let datasets = [Dataset::default().marker(Marker::Braille).data(&data)];
+    /// let mut chart = Chart::<&str, &str>::default();
+    /// chart
+    ///     .x_axis(Axis::default().bounds([0.0, 1.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 1.0]))
+    ///     .datasets(&datasets);
+    /// let dots = chart.braille_dots(&Rect::new(0, 0, 2, 4));
+    /// assert_eq!(dots[0], vec![DOTS[0][0] as u8, 0]);
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "testing"))]
+    pub fn braille_dots(&self, area: &Rect) -> Vec<Vec<u8>> {
+        let plane = self.plane(&self.layout(&self.chart_area(area)).graph_area);
+        let mut dots = vec![vec![0; plane.area.width as usize]; plane.area.height as usize];
+        if plane.area.width < 1 || plane.area.height < 1 {
+            return dots;
+        }
+        for dataset in self.datasets.iter().filter(|d| {
+            d.marker == Marker::Braille && d.samples.is_empty() && d.bubbles.is_empty()
+        }) {
+            let points = dataset.points();
+            let dataset = Dataset {
+                data: &points,
+                error_data: &[],
+                ..*dataset
+            };
+            let coords = self.braille_points(&dataset, &self.shapes(&dataset, &plane), &plane);
+            let canvas = self.braille_canvas(&coords, dataset.style.fg, &plane);
+            for (row, painted) in dots.iter_mut().zip(canvas.braille_dots(&plane.area)) {
+                for (cell, painted) in row.iter_mut().zip(painted) {
+                    *cell |= painted;
+                }
+            }
+        }
+        dots
+    }
+
+    /// Draws a dataset in the graph area
+    fn plot(&self, dataset: &Dataset, plane: &Plane, buf: &mut Buffer) {
+        let graph_area = &plane.area;
+        let shapes = self.shapes(dataset, plane);
+        match dataset.marker {
+            Marker::Dot | Marker::Custom(_) => {
+                let mut cells = shapes
+                    .points
+                    .iter()
+                    .filter_map(|&(x, y)| self.project_in(x, y, plane))
+                    .collect::<Vec<(u16, u16)>>();
+                for &segment in &shapes.segments {
+                    let horizontal = self.is_horizontal(segment, plane);
+                    for (x, y) in self.sample(segment, plane) {
+                        if let Some((x, y)) = self.project_in(x, y, plane) {
+                            cells.push((x, y));
+                            if dataset.line_width > 1 {
+                                // Nothing is added when the graph area is a single cell thick
+                                let extra = if horizontal {
+                                    if y + 1 < graph_area.bottom() {
+                                        Some((x, y + 1))
+                                    } else if y > graph_area.top() {
+                                        Some((x, y - 1))
+                                    } else {
+                                        None
+                                    }
+                                } else if x + 1 < graph_area.right() {
+                                    Some((x + 1, y))
+                                } else if x > graph_area.left() {
+                                    Some((x - 1, y))
+                                } else {
+                                    None
+                                };
+                                cells.extend(extra);
+                            }
+                        }
+                    }
+                }
+                cells.extend(
+                    shapes.fill
+                        .iter()
+                        .filter_map(|&(x, y)| self.project_in(x, y, plane)),
+                );
+                let symbol = match dataset.marker {
+                    Marker::Custom(symbol) => symbol,
+                    _ => symbols::DOT,
+                };
+                let width = max(symbol.width(), 1) as u16;
+                if width > graph_area.width {
+                    return;
+                }
+                for (x, y) in cells {
+                    // Wide symbols are aligned on a grid of their own width so that neighbouring
+                    // points cannot cover half of each other
+                    let x = graph_area.left() + (x - graph_area.left()) / width * width;
+                    let x = x.min(graph_area.right() - width);
+                    buf.get_mut(x, y)
+                        .set_symbol(symbol)
+                        .set_fg(dataset.style.fg)
+                        .set_bg(dataset.style.bg);
+                    // The cells hidden by the symbol are left empty so that nothing is printed
+                    // over it
+                    for x in x + 1..x + width {
+                        buf.get_mut(x, y)
+                            .set_symbol("")
+                            .set_fg(dataset.style.fg)
+                            .set_bg(dataset.style.bg);
+                    }
+                }
+            }
+            Marker::Braille => {
+                let coords = self.braille_points(dataset, &shapes, plane);
+                self.braille_canvas(&coords, dataset.style.fg, plane)
+                    .draw(graph_area, buf);
+            }
+        }
+        if let Some((symbol, fill)) = shapes.pattern {
+            for (x, y) in fill.into_iter()
+                .filter_map(|(x, y)| self.project_in(x, y, plane))
+            {
+                let cell = buf.get_mut(x, y);
+                if cell.symbol == " " {
+                    cell.set_symbol(symbol)
+                        .set_fg(dataset.style.fg)
+                        .set_bg(dataset.style.bg);
+                }
+            }
+        }
+    }
+
+    /// Returns the style used to draw an axis. When the axis has no foreground color of its own
+    /// and the chart has a background, a color contrasting with this background is picked so that
+    /// the axis lines remain visible.
+    fn axis_style(&self, style: Style) -> Style {
+        if style.fg == Color::Reset && self.style.bg != Color::Reset {
+            style.fg(Color::readable_over(self.style.bg))
+        } else {
+            style
+        }
+    }
+
+    /// Returns the number of columns taken on the left of the graph area by the labels and the
+    /// line of the vertical axis, the block excepted, when there is enough room for them.
+    /// Reserving this margin next to other widgets, or sharing it through a `SharedXAxis`, keeps
+    /// the graph areas aligned.
+    pub fn required_left_margin(&self) -> u16 {
+        let (horizontal, vertical) = self.axes();
+        let margin = if vertical.has_labels() {
+            labels_width(&horizontal, &vertical) + self.label_gap + 1
+        } else {
+            0
+        };
+        max(margin, self.shared_left_margin())
+    }
+
+    /// Returns the area left to the chart once the block has been taken into account.
+    fn chart_area(&self, area: &Rect) -> Rect {
+        match self.block {
+            Some(ref b) => b.inner(area),
+            None => *area,
+        }
+    }
+
+    /// Compute the internal layout of the chart given the area, letterboxing it if needed.
+    fn layout(&self, area: &Rect) -> ChartLayout {
+        self.layout_with(area, &self.axes())
+    }
+
+    /// Compute the layout given the area and the views of the horizontal and vertical axes,
+    /// whose labels are only generated once by the caller.
+    fn layout_with(&self, area: &Rect, axes: &(AxisView, AxisView)) -> ChartLayout {
+        let layout = self.layout_in(area, axes);
+        let ratio = match self.letterbox {
+            Some(ratio) if ratio > 0.0 => ratio,
+            _ => return layout,
+        };
+        let graph_area = layout.graph_area;
+        if graph_area.width == 0 || graph_area.height == 0 {
+            return layout;
+        }
+        // The space around the graph area does not depend on the size of the chart, shrinking
+        // the chart therefore shrinks the graph area by the same amount.
+        let width = max((f64::from(graph_area.height) * ratio) as u16, 1);
+        let height = max((f64::from(graph_area.width) / ratio) as u16, 1);
+        if width < graph_area.width {
+            let excess = graph_area.width - width;
+            self.layout_in(
+                &Rect::new(area.x + excess / 2, area.y, area.width - excess, area.height),
+                axes,
+            )
+        } else if height < graph_area.height {
+            let excess = graph_area.height - height;
+            self.layout_in(
+                &Rect::new(area.x, area.y + excess / 2, area.width, area.height - excess),
+                axes,
+            )
+        } else {
+            layout
+        }
+    }
+
+    /// Compute the internal layout of the chart given the area. If the area is too small some
+    /// elements may be automatically hidden, starting with the ones sacrificed to reach the
+    /// minimum size of the graph area.
+    fn layout_in(&self, area: &Rect, axes: &(AxisView, AxisView)) -> ChartLayout {
+        let (min_width, min_height) = self.min_graph_area;
+        let hidden = [
+            Hidden::default(),
+            Hidden {
+                vertical_labels: true,
+                ..Hidden::default()
+            },
+            Hidden {
+                horizontal_labels: true,
+                ..Hidden::default()
+            },
+            Hidden {
+                vertical_labels: true,
+                horizontal_labels: true,
+                ..Hidden::default()
+            },
+            Hidden {
+                vertical_labels: true,
+                horizontal_labels: true,
+                axes: true,
+            },
+        ];
+        let mut layout = ChartLayout::default();
+        for hidden in &hidden {
+            layout = self.layout_without(area, hidden, axes);
+            if layout.graph_area.width >= min_width && layout.graph_area.height >= min_height {
+                break;
+            }
+        }
+        layout
+    }
+
+    fn layout_without(
+        &self,
+        area: &Rect,
+        hidden: &Hidden,
+        (horizontal, vertical): &(AxisView, AxisView),
+    ) -> ChartLayout {
         let mut layout = ChartLayout::default();
         if area.height == 0 || area.width == 0 {
             return layout;
         }
+        if self.compact {
+            layout.graph_area = *area;
+            return layout;
+        }
         let mut x = area.left();
         let mut y = area.bottom() - 1;
 
-        if self.x_axis.labels.is_some() && y > area.top() {
-            layout.label_x = Some(y);
-            y -= 1;
+        if horizontal.has_labels() && !hidden.horizontal_labels {
+            let height = horizontal.labels_height();
+            if y >= area.top().saturating_add(height + self.label_gap) {
+                layout.label_x = Some(y + 1 - height);
+                y -= height + self.label_gap;
+            }
         }
 
-        if let Some(y_labels) = self.y_axis.labels {
-            let mut max_width = y_labels
-                .iter()
-                .fold(0, |acc, l| max(l.as_ref().width(), acc))
-                as u16;
-            if let Some(x_labels) = self.x_axis.labels {
-                if x_labels.len() > 0 {
-                    max_width = max(max_width, x_labels[0].as_ref().width() as u16);
-                }
-            }
-            if x + max_width < area.right() {
+        if vertical.has_labels() && !hidden.vertical_labels {
+            let max_width = max(
+                labels_width(horizontal, vertical) + self.label_gap,
+                self.shared_left_margin().saturating_sub(1),
+            );
+            if x.saturating_add(max_width) < area.right() {
                 layout.label_y = Some(x);
                 x += max_width;
             }
         }
 
-        if self.x_axis.labels.is_some() && y > area.top() {
+        if horizontal.has_labels() && !hidden.axes && y > area.top() {
             layout.axis_x = Some(y);
             y -= 1;
         }
 
-        if self.y_axis.labels.is_some() && x + 1 < area.right() {
+        if vertical.has_labels() && !hidden.axes && x.saturating_add(1) < area.right() {
             layout.axis_y = Some(x);
             x += 1;
         }
 
-        if x < area.right() && y > 1 {
-            layout.graph_area = Rect::new(x, area.top(), area.right() - x, y - area.top() + 1);
+        let margin = area.left().saturating_add(self.shared_left_margin());
+        if x < margin && margin < area.right() {
+            x = margin;
         }
 
-        if let Some(title) = self.x_axis.title {
+        let top = area.top().saturating_add(self.top_padding);
+        let right = area.right().saturating_sub(self.right_padding);
+        if x < right && y > 1 && y >= top {
+            layout.graph_area = Rect::new(x, top, right - x, y - top + 1);
+        }
+
+        if let Some(title) = horizontal.title {
             let w = title.width() as u16;
             if w < layout.graph_area.width && layout.graph_area.height > 2 {
                 layout.title_x = Some((x + layout.graph_area.width - w, y));
             }
         }
 
-        if let Some(title) = self.y_axis.title {
+        if let Some(title) = vertical.title {
             let w = title.width() as u16;
             if w + 1 < layout.graph_area.width && layout.graph_area.height > 2 {
                 layout.title_y = Some((x + 1, area.top()));
             }
         }
 
-        if let Some(inner_width) = self.datasets.iter().map(|d| d.name.width() as u16).max() {
+        let entries = self.legend_entries();
+        let widths = entries.iter().map(|&(swatch, name, _)| {
+            name.width() as u16 + if swatch.is_some() { 2 } else { 0 }
+        });
+        if let Some(inner_width) = widths.max() {
             let legend_width = inner_width + 2;
-            let legend_height = self.datasets.len() as u16 + 2;
+            let legend_height = entries.len() as u16 + 2;
             if legend_width < layout.graph_area.width / 3
                 && legend_height < layout.graph_area.height / 3
             {
@@ -349,70 +2547,103 @@ where
             None => *area,
         };
 
-        let layout = self.layout(&chart_area);
+        let axes = self.axes();
+        let layout = self.layout_with(&chart_area, &axes);
         let graph_area = layout.graph_area;
         if graph_area.width < 1 || graph_area.height < 1 {
             return;
         }
 
         self.background(&chart_area, buf, self.style.bg);
+        let (horizontal, vertical) = axes;
 
         if let Some((x, y)) = layout.title_x {
-            let title = self.x_axis.title.unwrap();
-            buf.set_string(x, y, title, &self.x_axis.style);
+            let title = horizontal.title.unwrap();
+            buf.set_string(x, y, title, &horizontal.style);
         }
 
         if let Some((x, y)) = layout.title_y {
-            let title = self.y_axis.title.unwrap();
-            buf.set_string(x, y, title, &self.y_axis.style);
+            let title = vertical.title.unwrap();
+            buf.set_string(x, y, title, &vertical.style);
         }
 
         if let Some(y) = layout.label_x {
-            let labels = self.x_axis.labels.unwrap();
-            let total_width = labels.iter().fold(0, |acc, l| l.as_ref().width() + acc) as u16;
-            let labels_len = labels.len() as u16;
-            if total_width < graph_area.width && labels_len > 1 {
-                for (i, label) in labels.iter().enumerate() {
+            let mut labels = horizontal.positioned_labels(graph_area.width, false);
+            // Horizontal labels end right before their tick, every other label is dropped until
+            // none of them touches the previous one
+            let fits = |labels: &[(u16, &str)]| {
+                labels
+                    .windows(2)
+                    .all(|pair| pair[0].0 + (pair[1].1.width() as u16) < pair[1].0)
+            };
+            while horizontal.label_rotation == Rotation::Horizontal && !fits(&labels) {
+                labels = thin_out(labels, 2);
+            }
+            if horizontal.label_rotation != Rotation::Horizontal {
+                for &(offset, label) in &labels {
+                    let x = graph_area.left() + offset;
+                    for (j, g) in label.graphemes(true).enumerate() {
+                        let dx = if horizontal.label_rotation == Rotation::Diagonal {
+                            j as u16
+                        } else {
+                            0
+                        };
+                        if x + dx < chart_area.right() && y + (j as u16) < chart_area.bottom() {
+                            buf.get_mut(x + dx, y + j as u16)
+                                .set_symbol(g)
+                                .set_style(horizontal.labels_style);
+                        }
+                    }
+                }
+            } else {
+                for &(offset, label) in &labels {
                     buf.set_string(
-                        graph_area.left() + i as u16 * (graph_area.width - 1) / (labels_len - 1)
-                            - label.as_ref().width() as u16,
+                        (graph_area.left() + offset).saturating_sub(label.width() as u16),
                         y,
-                        label.as_ref(),
-                        &self.x_axis.labels_style,
+                        label,
+                        &horizontal.labels_style,
                     );
                 }
             }
         }
 
         if let Some(x) = layout.label_y {
-            let labels = self.y_axis.labels.unwrap();
-            let labels_len = labels.len() as u16;
-            for (i, label) in labels.iter().enumerate() {
-                let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
+            let labels = distinct_rows(vertical.positioned_labels(graph_area.height, true));
+            // The labels are right-aligned against the axis, the gap excepted
+            let right = layout.axis_y.unwrap_or_else(|| graph_area.left());
+            let width = right.saturating_sub(x + self.label_gap) as usize;
+            for (dy, label) in labels {
                 if dy < graph_area.bottom() {
-                    buf.set_string(
+                    buf.set_stringn_aligned(
                         x,
                         graph_area.bottom() - 1 - dy,
-                        label.as_ref(),
-                        &self.y_axis.labels_style,
+                        label,
+                        width,
+                        Alignment::Right,
+                        &vertical.labels_style,
                     );
                 }
             }
         }
 
+        let horizontal_style = self.axis_style(horizontal.style);
+        let vertical_style = self.axis_style(vertical.style);
+
         if let Some(y) = layout.axis_x {
+            let symbol = horizontal.line_symbol.unwrap_or(symbols::line::HORIZONTAL);
             for x in graph_area.left()..graph_area.right() {
                 buf.get_mut(x, y)
-                    .set_symbol(symbols::line::HORIZONTAL)
-                    .set_style(self.x_axis.style);
+                    .set_symbol(symbol)
+                    .set_style(horizontal_style);
             }
         }
 
         if let Some(x) = layout.axis_y {
+            let symbol = vertical.line_symbol.unwrap_or(symbols::line::VERTICAL);
             for y in graph_area.top()..graph_area.bottom() {
                 buf.get_mut(x, y)
-                    .set_symbol(symbols::line::VERTICAL)
-                    .set_style(self.y_axis.style);
+                    .set_symbol(symbol)
+                    .set_style(vertical_style);
             }
         }
 
@@ -420,41 +2651,108 @@ where
             if let Some(x) = layout.axis_y {
                 buf.get_mut(x, y)
                     .set_symbol(symbols::line::BOTTOM_LEFT)
-                    .set_style(self.x_axis.style);
-            }
-        }
-
-        for dataset in self.datasets {
-            match dataset.marker {
-                Marker::Dot => for &(x, y) in dataset.data.iter().filter(|&&(x, y)| {
-                    !(x < self.x_axis.bounds[0] || x > self.x_axis.bounds[1]
-                        || y < self.y_axis.bounds[0]
-                        || y > self.y_axis.bounds[1])
-                }) {
-                    let dy = ((self.y_axis.bounds[1] - y) * f64::from(graph_area.height - 1)
-                        / (self.y_axis.bounds[1] - self.y_axis.bounds[0]))
-                        as u16;
-                    let dx = ((x - self.x_axis.bounds[0]) * f64::from(graph_area.width - 1)
-                        / (self.x_axis.bounds[1] - self.x_axis.bounds[0]))
-                        as u16;
-
-                    buf.get_mut(graph_area.left() + dx, graph_area.top() + dy)
-                        .set_symbol(symbols::DOT)
-                        .set_fg(dataset.style.fg)
-                        .set_bg(dataset.style.bg);
-                },
-                Marker::Braille => {
-                    Canvas::default()
-                        .background_color(self.style.bg)
-                        .x_bounds(self.x_axis.bounds)
-                        .y_bounds(self.y_axis.bounds)
-                        .paint(|ctx| {
-                            ctx.draw(&Points {
-                                coords: dataset.data,
-                                color: dataset.style.fg,
-                            });
-                        })
-                        .draw(&graph_area, buf);
+                    .set_style(horizontal_style);
+            }
+        }
+
+        if let (Some(y), true) = (layout.axis_x, horizontal.ticks_visible) {
+            for (offset, _) in horizontal.positioned_labels(graph_area.width, false) {
+                buf.get_mut(graph_area.left() + offset, y)
+                    .set_symbol(symbols::line::HORIZONTAL_DOWN)
+                    .set_style(horizontal_style);
+            }
+        }
+
+        if let (Some(x), true) = (layout.axis_y, vertical.ticks_visible) {
+            for (offset, _) in vertical.positioned_labels(graph_area.height, true) {
+                buf.get_mut(x, graph_area.bottom() - 1 - offset)
+                    .set_symbol(symbols::line::VERTICAL_LEFT)
+                    .set_style(vertical_style);
+            }
+        }
+
+        if let Some((top, bottom)) = self.background_gradient {
+            let last = f64::from(graph_area.height.saturating_sub(1).max(1));
+            for (i, y) in (graph_area.top()..graph_area.bottom()).enumerate() {
+                if let Some(color) = mix(top, bottom, i as f64 / last) {
+                    let row = Rect::new(graph_area.left(), y, graph_area.width, 1);
+                    self.background(&row, buf, color);
+                }
+            }
+        }
+
+        if self.dot_grid {
+            let columns = horizontal.positioned_labels(graph_area.width, false);
+            for (dy, _) in vertical.positioned_labels(graph_area.height, true) {
+                for &(dx, _) in &columns {
+                    buf.get_mut(graph_area.left() + dx, graph_area.bottom() - 1 - dy)
+                        .set_symbol(symbols::pattern::DOTS)
+                        .set_fg(Color::DarkGray);
+                }
+            }
+        }
+
+        let plane = self.plane(&graph_area);
+        self.draw_fill_between(&plane, buf);
+
+        let zero_lines = [
+            (self.x_axis.zero_line_color, ReferenceLine::vline(0.0)),
+            (self.y_axis.zero_line_color, ReferenceLine::hline(0.0)),
+        ];
+        for &(color, line) in &zero_lines {
+            if let Some(color) = color {
+                let style = Style::default().fg(color).bg(self.style.bg);
+                self.draw_reference_line(&line.style(style), &plane, buf);
+            }
+        }
+
+        for line in self.reference_lines {
+            self.draw_reference_line(line, &plane, buf);
+        }
+
+        let mut datasets = self.datasets.iter().collect::<Vec<&Dataset>>();
+        datasets.sort_by_key(|d| d.z);
+
+        for dataset in &datasets {
+            if !dataset.samples.is_empty() {
+                self.plot_histogram(dataset, &plane, buf);
+            } else if !dataset.bubbles.is_empty() {
+                self.plot_bubbles(dataset, &plane, buf);
+            } else if dataset.error_data.is_empty() {
+                self.plot(dataset, &plane, buf);
+            } else {
+                self.draw_error_bars(dataset, &plane, buf);
+                let points = dataset.points();
+                let dataset = Dataset {
+                    data: &points,
+                    error_data: &[],
+                    ..**dataset
+                };
+                self.plot(&dataset, &plane, buf);
+            }
+        }
+
+        if !self.empty_message.is_empty() && self.is_empty(&plane) {
+            let width = (self.empty_message.width() as u16).min(graph_area.width);
+            buf.set_stringn(
+                graph_area.left() + (graph_area.width - width) / 2,
+                graph_area.top() + graph_area.height / 2,
+                self.empty_message,
+                width as usize,
+                &self.style,
+            );
+        }
+
+        for dataset in &datasets {
+            for &(i, label) in dataset.point_labels {
+                let point = dataset
+                    .points()
+                    .get(i)
+                    .and_then(|&(x, y)| self.project_in(x, y, &plane));
+                if let Some((x, y)) = point {
+                    if x + 1 + label.width() as u16 <= graph_area.right() {
+                        buf.set_string(x + 1, y, label, &dataset.style);
+                    }
                 }
             }
         }
@@ -463,14 +2761,1281 @@ where
             Block::default()
                 .borders(Borders::ALL)
                 .draw(&legend_area, buf);
-            for (i, dataset) in self.datasets.iter().enumerate() {
-                buf.set_string(
-                    legend_area.x + 1,
-                    legend_area.y + 1 + i as u16,
-                    dataset.name,
-                    &dataset.style,
-                );
+            for (i, &(swatch, name, style)) in self.legend_entries().iter().enumerate() {
+                let (x, y) = (legend_area.x + 1, legend_area.y + 1 + i as u16);
+                match swatch {
+                    Some(swatch) => {
+                        buf.set_string(x, y, swatch, &style);
+                        buf.set_string(x + 2, y, name, &style);
+                    }
+                    None => buf.set_string(x, y, name, &style),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use symbols::line;
+    use widgets::canvas::DOTS;
+    use widgets::Bordered;
+
+    #[test]
+    fn project_matches_plotted_points() {
+        let data = [(0.0, 0.0), (3.3, 7.1), (10.0, 10.0)];
+        let datasets = [Dataset::default().marker(Marker::Dot).data(&data)];
+        let mut chart = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        for &(x, y) in &data {
+            let (cx, cy) = chart.project(x, y, &area).unwrap();
+            assert_eq!(buf.get(cx, cy).symbol, symbols::DOT);
+        }
+        assert_eq!(chart.project(10.5, 5.0, &area), None);
+    }
+
+    #[test]
+    fn padding_keeps_upper_bounds_inside_the_graph_area() {
+        let data = [(10.0, 10.0)];
+        let datasets = [Dataset::default().marker(Marker::Dot).data(&data)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets)
+            .right_padding(1)
+            .top_padding(1);
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        assert_eq!(chart.project(10.0, 10.0, &area), Some((8, 1)));
+        assert_eq!(buf.get(8, 1).symbol, symbols::DOT);
+        assert_eq!(buf.get(9, 1).symbol, " ");
+        assert_eq!(buf.get(8, 0).symbol, " ");
+    }
+
+    #[test]
+    fn point_labels_are_drawn_next_to_their_point() {
+        let data = [(1.0, 1.0), (5.0, 5.0), (9.0, 9.0)];
+        let labels = [(1, "mid"), (2, "top"), (3, "none")];
+        let datasets = [Dataset::default().data(&data).point_labels(&labels)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        let (x, y) = chart.project(5.0, 5.0, &area).unwrap();
+        assert_eq!(buf.get(x, y).symbol, symbols::DOT);
+        assert_eq!(buf.get(x + 1, y).symbol, "m");
+        assert_eq!(buf.get(x + 3, y).symbol, "d");
+        // Not enough room on the right of the last point
+        let (x, y) = chart.project(9.0, 9.0, &area).unwrap();
+        assert_eq!(buf.get(x + 1, y).symbol, " ");
+    }
+
+    #[test]
+    fn datasets_are_drawn_according_to_their_z_index() {
+        let data = [(5.0, 5.0)];
+        let datasets = [
+            Dataset::default()
+                .data(&data)
+                .style(Style::default().fg(Color::Red))
+                .z(1),
+            Dataset::default()
+                .data(&data)
+                .style(Style::default().fg(Color::Blue)),
+        ];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        assert_eq!(buf.get(5, 5).style.fg, Color::Red);
+    }
+
+    #[test]
+    fn axes_contrast_with_the_chart_background() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 10.0])
+                    .labels(&["0", "10"])
+                    .style(Style::default().fg(Color::Red)),
+            )
+            .style(Style::default().bg(Color::Black));
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        assert_eq!(buf.get(5, 8).symbol, symbols::line::HORIZONTAL);
+        assert_eq!(buf.get(5, 8).style.fg, Color::White);
+        // Explicit colors are left untouched
+        assert_eq!(buf.get(2, 5).symbol, symbols::line::VERTICAL);
+        assert_eq!(buf.get(2, 5).style.fg, Color::Red);
+    }
+
+    #[test]
+    fn number_format_separators() {
+        let us = NumberFormat::default().thousands_sep(',');
+        assert_eq!(us.format(1234567.5), "1,234,567.5");
+        assert_eq!(us.format(123.0), "123");
+        assert_eq!(us.format(-1000.0), "-1,000");
+        let eu = NumberFormat::default().decimal_sep(',').thousands_sep('.');
+        assert_eq!(eu.format(1234567.5), "1.234.567,5");
+        assert_eq!(eu.precision(3).format(0.5), "0,500");
+        assert_eq!(NumberFormat::default().format(1234567.5), "1234567.5");
+    }
+
+    #[test]
+    fn point_at_finds_the_closest_point() {
+        let first = [(1.0, 1.0), (5.0, 5.0)];
+        let second = [(2.0, 8.0), (5.0, 6.0)];
+        let datasets = [
+            Dataset::default().data(&first),
+            Dataset::default().data(&second),
+        ];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 11);
+
+        let (x, y) = chart.project(5.0, 5.0, &area).unwrap();
+        assert_eq!(chart.point_at(x, y, &area), Some((0, 1)));
+        let (x, y) = chart.project(2.0, 8.0, &area).unwrap();
+        assert_eq!(chart.point_at(x, y, &area), Some((1, 0)));
+        assert_eq!(chart.point_at(x + 1, y + 1, &area), Some((1, 0)));
+        assert_eq!(chart.point_at(9, 9, &area), None);
+    }
+
+    #[test]
+    fn thick_lines_occupy_two_cells() {
+        let data = [(0.0, 5.0), (10.0, 5.0), (10.0, 0.0)];
+        let datasets = [
+            Dataset::default()
+                .data(&data)
+                .graph_type(GraphType::Line)
+                .line_width(2),
+        ];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        for x in 0..11 {
+            assert_eq!(buf.get(x, 5).symbol, symbols::DOT);
+            assert_eq!(buf.get(x, 6).symbol, symbols::DOT);
+            assert_eq!(buf.get(x, 4).symbol, " ");
+        }
+        // The vertical line is thickened on its left as it lies on the right edge
+        for y in 5..11 {
+            assert_eq!(buf.get(9, y).symbol, symbols::DOT);
+        }
+        assert_eq!(buf.get(8, 8).symbol, " ");
+    }
+
+    #[test]
+    fn lines_on_logarithmic_axes_have_no_gaps() {
+        let data = [(0.0, 1.0), (10.0, 1000.0)];
+        let datasets = [Dataset::default().data(&data).graph_type(GraphType::Line)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([1.0, 1000.0]).scale(Scale::Log))
+            .datasets(&datasets)
+            .compact(true);
+        let area = Rect::new(0, 0, 30, 15);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        // The line is straight on screen, through every row and column
+        for y in 0..15 {
+            assert!((0..30).any(|x| buf.get(x, y).symbol == symbols::DOT), "{}", buf);
+        }
+        for x in 0..30 {
+            assert!((0..15).any(|y| buf.get(x, y).symbol == symbols::DOT), "{}", buf);
+        }
+    }
+
+    #[test]
+    fn thick_lines_fit_in_graph_areas_one_cell_thick() {
+        let vertical = [(0.0, 0.0), (0.0, 10.0)];
+        let horizontal = [(0.0, 0.0), (10.0, 0.0)];
+        for &(data, area) in &[
+            (&vertical, Rect::new(0, 0, 1, 5)),
+            (&vertical, Rect::new(3, 2, 1, 5)),
+            (&horizontal, Rect::new(0, 0, 5, 1)),
+            (&horizontal, Rect::new(3, 2, 5, 1)),
+        ] {
+            let datasets = [
+                Dataset::default()
+                    .data(data)
+                    .graph_type(GraphType::Line)
+                    .line_width(2),
+            ];
+            let mut chart: Chart<&str, &str> = Chart::default();
+            chart
+                .x_axis(Axis::default().bounds([0.0, 10.0]))
+                .y_axis(Axis::default().bounds([0.0, 10.0]))
+                .datasets(&datasets)
+                .compact(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 10));
+            chart.draw(&area, &mut buf);
+            for y in 0..10 {
+                for x in 0..10 {
+                    let inside = x >= area.left() && x < area.right() && y >= area.top()
+                        && y < area.bottom();
+                    assert_eq!(buf.get(x, y).symbol == symbols::DOT, inside, "{:?}", area);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn thick_braille_lines_are_offset_by_a_cell_on_screen() {
+        let rows = |y: f64, scale: Scale, bounds: [f64; 2]| {
+            let data = [(0.0, y), (10.0, y)];
+            let datasets = [
+                Dataset::default()
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .data(&data)
+                    .line_width(2),
+            ];
+            let mut chart: Chart<&str, &str> = Chart::default();
+            chart
+                .x_axis(Axis::default().bounds([0.0, 10.0]))
+                .y_axis(Axis::default().bounds(bounds).scale(scale))
+                .datasets(&datasets);
+            chart
+                .braille_dots(&Rect::new(0, 0, 11, 4))
+                .iter()
+                .map(|row| row.iter().all(|&dots| dots != 0))
+                .collect::<Vec<bool>>()
+        };
+        // One cell below on a logarithmic axis, whose cells do not span the same values
+        assert_eq!(rows(10.0, Scale::Log, [1.0, 1000.0]), vec![false, false, true, true]);
+        // One cell above on the bottom edge
+        assert_eq!(rows(0.0, Scale::Linear, [0.0, 3.0]), vec![false, false, true, true]);
+        assert_eq!(rows(3.0, Scale::Linear, [0.0, 3.0]), vec![true, true, false, false]);
+    }
+
+    #[test]
+    fn rotated_labels_are_written_below_their_tick() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 10.0])
+                    .labels(&["start", "end"])
+                    .label_rotation(Rotation::Vertical),
+            )
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]));
+        let area = Rect::new(0, 0, 20, 12);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        // The x axis lies right above the 5 rows taken by the labels
+        assert_eq!(buf.get(10, 6).symbol, symbols::line::HORIZONTAL);
+        for (j, c) in "start".chars().enumerate() {
+            assert_eq!(buf.get(3, 7 + j as u16).symbol, c.to_string());
+        }
+        for (j, c) in "end".chars().enumerate() {
+            assert_eq!(buf.get(19, 7 + j as u16).symbol, c.to_string());
+        }
+    }
+
+    #[test]
+    fn ring_dataset_drops_the_oldest_points() {
+        let mut ring = RingDataset::new(3);
+        assert!(ring.is_empty());
+        for i in 0..3 {
+            ring.push((f64::from(i), 0.0));
+        }
+        assert_eq!(ring.as_slice(), &[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        for i in 3..8 {
+            ring.push((f64::from(i), 0.0));
+        }
+        assert_eq!(ring.len(), 3);
+        assert_eq!(ring.as_slice(), &[(5.0, 0.0), (6.0, 0.0), (7.0, 0.0)]);
+
+        let mut empty = RingDataset::new(0);
+        empty.push((1.0, 1.0));
+        assert!(empty.as_slice().is_empty());
+    }
+
+    #[test]
+    fn wide_markers_leave_their_continuation_cell_empty() {
+        let area = Rect::new(0, 0, 10, 10);
+        let data = [(0.0, 0.0), (3.0, 0.0), (4.0, 0.0), (9.0, 0.0)];
+        let mut buf = Buffer::empty(area);
+        Chart::<&str, &str>::default()
+            .x_axis(Axis::default().bounds([0.0, 9.0]))
+            .y_axis(Axis::default().bounds([0.0, 9.0]))
+            .datasets(&[Dataset::default()
+                .marker(Marker::Custom("🐟"))
+                .data(&data)])
+            .draw(&area, &mut buf);
+
+        let symbols = (0..10).map(|x| buf.get(x, 9).symbol.as_str()).collect::<Vec<&str>>();
+        assert_eq!(symbols, vec!["🐟", "", "🐟", "", "🐟", "", " ", " ", "🐟", ""]);
+    }
+
+    #[test]
+    fn labels_can_be_placed_at_given_values() {
+        let area = Rect::new(0, 0, 22, 12);
+        let mut buf = Buffer::empty(area);
+        let mut chart = Chart::default();
+        chart
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .label_rotation(Rotation::Vertical)
+                    .labels_at(&[(50.0, "x"), (150.0, "out")]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .labels_at(&[(50.0, "y")]),
+            )
+            .empty_message("")
+            .draw(&area, &mut buf);
+
+        let layout = chart.layout(&area);
+        let graph_area = layout.graph_area;
+        let (x, y) = chart.project(50.0, 50.0, &area).unwrap();
+        assert_eq!(x, graph_area.left() + (graph_area.width - 1) / 2);
+        assert_eq!(buf.get(x, layout.label_x.unwrap()).symbol, "x");
+        assert_eq!(buf.get(0, y).symbol, "y");
+        assert!(!buf.to_string().contains('o'));
+    }
+
+    #[test]
+    fn wide_labels_are_thinned_out_when_they_overlap() {
+        let months = ["１月", "２月", "１１月", "１２月"];
+        let draw = |width| {
+            let area = Rect::new(0, 0, width, 5);
+            let mut buf = Buffer::empty(area);
+            Chart::<&str, &str>::default()
+                .x_axis(Axis::default().bounds([0.0, 3.0]).labels(&months))
+                .y_axis(Axis::default().bounds([0.0, 1.0]).labels(&["0", "1"]))
+                .draw(&area, &mut buf);
+            buf.to_string().lines().last().unwrap().to_owned()
+        };
+        assert_eq!(draw(25), " １月      １１月        ");
+        assert_eq!(draw(26), " １月  ２月 １１月 １２月 ");
+    }
+
+    #[test]
+    fn areas_are_filled_up_to_the_baseline() {
+        let area = Rect::new(0, 0, 5, 11);
+        let mut buf = Buffer::empty(area);
+        let data = [(0.0, 2.0), (10.0, 2.0)];
+        Chart::<&str, &str>::default()
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&[Dataset::default()
+                .graph_type(GraphType::Area)
+                .baseline(8.0)
+                .data(&data)])
+            .draw(&area, &mut buf);
+
+        for x in 0..5 {
+            let column = (0..11).map(|y| buf.get(x, y).symbol.as_str()).collect::<String>();
+            assert_eq!(column, "  •••••••  ");
+        }
+    }
+
+    #[test]
+    fn letterboxed_graph_areas_are_centered() {
+        let area = Rect::new(0, 0, 40, 5);
+        let mut chart = Chart::<&str, &str>::default();
+        chart.letterbox(2.0);
+
+        let graph_area = chart.layout(&area).graph_area;
+        assert!(graph_area.width <= graph_area.height * 2);
+        assert_eq!(graph_area.height, 5);
+        assert_eq!(graph_area.left() - area.left(), area.right() - graph_area.right());
+
+        // Tall areas are letterboxed vertically
+        let area = Rect::new(0, 0, 10, 20);
+        let graph_area = chart.layout(&area).graph_area;
+        assert_eq!(graph_area, Rect::new(0, 7, 10, 5));
+    }
+
+    #[test]
+    fn tooltips_are_flipped_near_the_right_edge() {
+        let area = Rect::new(0, 0, 20, 10);
+        let chart = Chart::<&str, &str>::default();
+        let mut buf = Buffer::empty(area);
+        chart.tooltip(2, 5, "42", &area, &mut buf);
+        assert_eq!(buf.get(3, 4).symbol, line::TOP_LEFT);
+        assert_eq!(buf.get(4, 5).symbol, "4");
+
+        let mut buf = Buffer::empty(area);
+        chart.tooltip(18, 0, "42", &area, &mut buf);
+        assert_eq!(buf.get(14, 0).symbol, line::TOP_LEFT);
+        assert_eq!(buf.get(17, 2).symbol, line::BOTTOM_RIGHT);
+        assert_eq!(buf.get(15, 1).symbol, "4");
+        assert_eq!(buf.get(18, 0).symbol, " ");
+    }
+
+    #[test]
+    fn areas_can_be_filled_with_patterns() {
+        let area = Rect::new(0, 0, 5, 11);
+        let mut buf = Buffer::empty(area);
+        let data = [(0.0, 8.0), (10.0, 8.0)];
+        Chart::<&str, &str>::default()
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&[Dataset::default()
+                .graph_type(GraphType::Area)
+                .fill_pattern(Pattern::Hatch)
+                .data(&data)])
+            .draw(&area, &mut buf);
+
+        for x in 0..5 {
+            let column = (0..11).map(|y| buf.get(x, y).symbol.as_str()).collect::<String>();
+            assert_eq!(column, "  •╱╱╱╱╱╱╱╱");
+        }
+    }
+
+    #[test]
+    fn out_of_bounds_points_can_be_clamped() {
+        let area = Rect::new(0, 0, 10, 10);
+        let data = [(5.0, 20.0), (-1.0, -1.0)];
+        let datasets = [Dataset::default().data(&data)];
+        let mut chart = Chart::<&str, &str>::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 9.0]))
+            .y_axis(Axis::default().bounds([0.0, 9.0]))
+            .datasets(&datasets);
+
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert!(!buf.to_string().contains(symbols::DOT));
+
+        let mut buf = Buffer::empty(area);
+        chart.clip_mode(ClipMode::Clamp).draw(&area, &mut buf);
+        assert_eq!(buf.get(5, 0).symbol, symbols::DOT);
+        assert_eq!(buf.get(0, 9).symbol, symbols::DOT);
+    }
+
+    #[test]
+    fn ticks_are_drawn_at_the_labels_positions() {
+        let area = Rect::new(0, 0, 12, 6);
+        let mut buf = Buffer::empty(area);
+        Chart::<&str, &str>::default()
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 8.0])
+                    .labels(&["0", "4", "8"])
+                    .ticks_visible(true),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 3.0])
+                    .labels(&["0", "3"])
+                    .ticks_visible(true),
+            )
+            .empty_message("")
+            .draw(&area, &mut buf);
+
+        assert_eq!(
+            buf.to_string(),
+            "3┤          \n │          \n │          \n0┤          \n └┬───┬────┬\n 0   4    8 "
+        );
+    }
+
+    #[test]
+    fn single_labels_are_placed_at_axes_start() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["x"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["y"]));
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        // The y label sits next to the bottom of the vertical axis
+        assert_eq!(buf.get(0, 7).symbol, "y");
+        // The x label ends right before the start of the horizontal axis
+        assert_eq!(buf.get(1, 9).symbol, "x");
+    }
+
+    #[test]
+    fn dot_grid_marks_the_labels_intersections() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "5", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]).labels(&["0", "2", "4"]))
+            .dot_grid(true)
+            .empty_message("");
+        let area = Rect::new(0, 0, 14, 7);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        let mut expected = Vec::new();
+        for &y in &[0.0, 2.0, 4.0] {
+            for &x in &[0.0, 5.0, 10.0] {
+                let (cx, cy) = chart.project(x, y, &area).unwrap();
+                expected.push(buf.index_of(cx, cy));
+            }
+        }
+        expected.sort();
+        let dots = buf.content()
+            .iter()
+            .enumerate()
+            .filter(|&(_, c)| c.symbol == symbols::pattern::DOTS)
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        assert_eq!(dots, expected);
+        assert_eq!(buf.content()[dots[0]].style.fg, Color::DarkGray);
+    }
+
+    #[test]
+    fn degenerate_areas_do_not_overflow() {
+        let data = [(0.0, 0.0), (10.0, 10.0)];
+        let datasets = [Dataset::default().name("data").data(&data)];
+        let mut chart = Chart::default();
+        chart
+            .x_axis(Axis::default().title("x").bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().title("y").bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .datasets(&datasets)
+            .top_padding(2);
+        let values = [0, 1, 2, 3, 5, u16::MAX - 2, u16::MAX];
+        for &x in &values {
+            for &y in &values {
+                for &width in &values {
+                    for &height in &values {
+                        let area = Rect::new(x, y, width, height);
+                        for &padding in &[0, 1, u16::MAX] {
+                            chart.right_padding(padding).letterbox(2.0);
+                            chart.project(10.0, 10.0, &area);
+                            chart.point_at(x, y, &area);
+                        }
+                    }
+                }
             }
         }
     }
+
+    #[test]
+    fn error_bars_span_the_error_of_each_point() {
+        let data = [(5.0, 5.0, 2.0), (8.0, 9.0, 3.0)];
+        let datasets = [Dataset::default().marker(Marker::Dot).error_data(&data)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        let column = |x| (0..11).map(|y| buf.get(x, y).symbol.clone()).collect::<String>();
+        // From 3 to 7 with the point at 5
+        assert_eq!(column(5), "   ││•││   ");
+        // From 6 to 12, clipped to the upper bound
+        assert_eq!(column(8), "│•│││      ");
+    }
+
+    #[test]
+    fn hovered_values_are_formatted_in_the_tooltip() {
+        let data = [(2.0, 5.0)];
+        let datasets = [Dataset::default().data(&data)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 20, 11);
+        let text_row = |buf: &Buffer| {
+            (4..20)
+                .map(|x| buf.get(x, 5).symbol.clone())
+                .collect::<String>()
+        };
+
+        let mut buf = Buffer::empty(area);
+        assert_eq!(chart.hover_tooltip(3, 4, &area, &mut buf), Some((0, 0)));
+        assert_eq!(text_row(&buf), "│(2.00, 5.00)│  ");
+
+        chart.value_formatter(Box::new(|x, y| format!("x={} y={}ms", x, y)));
+        let mut buf = Buffer::empty(area);
+        chart.hover_tooltip(2, 5, &area, &mut buf);
+        assert_eq!(text_row(&buf), "│x=2 y=5ms│     ");
+
+        assert_eq!(chart.hover_tooltip(15, 1, &area, &mut buf), None);
+    }
+
+    #[test]
+    fn histograms_are_computed_from_raw_samples() {
+        let samples = [1.0, 1.0, 2.0];
+        let datasets = [Dataset::default().histogram(&samples, 2)];
+        assert_eq!(datasets[0].bin_counts([1.0, 2.0]), vec![2, 1]);
+
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([1.0, 2.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "██  \n██  \n████\n████");
+    }
+
+    #[test]
+    fn generated_labels_precision_depends_on_the_bounds() {
+        let labels = |bounds| {
+            Axis::<&str>::default()
+                .bounds(bounds)
+                .auto_labels(5)
+                .view()
+                .label_texts()
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<String>>()
+        };
+        let decimals = |label: &String| label.find('.').map_or(0, |i| label.len() - i - 1);
+
+        let small = labels([0.0, 0.01]);
+        let large = labels([0.0, 10000.0]);
+        assert_eq!(small, vec!["0.0000", "0.0025", "0.0050", "0.0075", "0.0100"]);
+        assert_eq!(large, vec!["0", "2500", "5000", "7500", "10000"]);
+        assert!(decimals(&small[1]) > decimals(&large[1]));
+
+        let fixed = Axis::<&str>::default()
+            .bounds([0.0, 0.01])
+            .auto_labels(2)
+            .label_format(NumberFormat::default().precision(1));
+        assert_eq!(fixed.view().label_texts(), vec!["0.0", "0.0"]);
+    }
+
+    #[test]
+    fn generated_labels_end_with_the_unit() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .y_axis(Axis::default().bounds([0.0, 20.0]).auto_labels(3).unit("ms"))
+            .empty_message("");
+        let area = Rect::new(0, 0, 10, 5);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        let rows = buf.to_string()
+            .lines()
+            .map(|line| line[..4].to_owned())
+            .collect::<Vec<String>>();
+        assert_eq!(rows, vec!["20ms", "    ", "10ms", "    ", " 0ms"]);
+
+        // Explicit labels are left untouched
+        let axis = Axis::default().labels(&["0", "1"]).unit("ms");
+        assert_eq!(axis.view().label_texts(), vec!["0", "1"]);
+    }
+
+    #[test]
+    fn empty_charts_display_a_placeholder() {
+        let data = [(20.0, 5.0)];
+        let datasets = [Dataset::default().data(&[]), Dataset::default().data(&data)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 3);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "           \n  No data  \n           ");
+
+        let mut buf = Buffer::empty(area);
+        chart.empty_message("").draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "           \n           \n           ");
+    }
+
+    #[test]
+    fn axes_lines_can_use_custom_symbols() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 10.0])
+                    .labels(&["0", "10"])
+                    .line_symbol(line::DOUBLE_HORIZONTAL),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 10.0])
+                    .labels(&["0", "10"])
+                    .line_symbol("."),
+            )
+            .empty_message("");
+        let area = Rect::new(0, 0, 8, 5);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "10.     \n  .     \n 0.     \n  └═════\n  0  10 ");
+    }
+
+    #[test]
+    fn left_margin_matches_the_layout() {
+        let area = Rect::new(0, 0, 20, 10);
+        let mut chart = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "1000"]));
+        let layout = chart.layout(&area);
+        assert_eq!(layout.label_y, Some(0));
+        assert_eq!(layout.axis_y, Some(4));
+        assert_eq!(chart.required_left_margin(), 5);
+        assert_eq!(layout.graph_area.left(), chart.required_left_margin());
+
+        chart.x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["-10000", "10"]));
+        assert_eq!(chart.required_left_margin(), 7);
+        assert_eq!(chart.layout(&area).graph_area.left(), 7);
+
+        chart.y_axis(Axis::default().bounds([0.0, 10.0]));
+        assert_eq!(chart.required_left_margin(), 0);
+        assert_eq!(chart.layout(&area).graph_area.left(), 0);
+    }
+
+    #[test]
+    fn charts_sharing_an_x_axis_are_aligned() {
+        let area = Rect::new(0, 0, 30, 10);
+        let mut prices = Chart::default();
+        prices
+            .x_axis(Axis::default().bounds([5.0, 6.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "1000"]));
+        let mut volumes: Chart<&str, &str> = Chart::default();
+        volumes.y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "5"]));
+        let mut empty: Chart<&str, &str> = Chart::default();
+
+        let mut shared = SharedXAxis::new([0.0, 10.0]);
+        for chart in &[&prices, &volumes, &empty] {
+            shared.reserve(chart.required_left_margin());
+        }
+        assert_eq!(shared.left_margin(), 5);
+        prices.shared_x_axis(shared);
+        volumes.shared_x_axis(shared);
+        empty.shared_x_axis(shared);
+
+        assert_eq!(prices.x_axis.bounds, [0.0, 10.0]);
+        let x = prices.layout(&area).graph_area.x;
+        assert_eq!(x, 5);
+        assert_eq!(volumes.layout(&area).graph_area.x, x);
+        assert_eq!(empty.layout(&area).graph_area.x, x);
+        for &value in &[0.0, 3.0, 10.0] {
+            assert_eq!(
+                volumes.project(value, 0.0, &area).map(|(x, _)| x),
+                prices.project(value, 0.0, &area).map(|(x, _)| x)
+            );
+        }
+    }
+
+    #[test]
+    fn crowded_labels_are_thinned_out() {
+        let labels = (0..20).map(|i| i.to_string()).collect::<Vec<String>>();
+        let draw = |axis: Axis<String>| {
+            let area = Rect::new(0, 0, 10, 5);
+            let mut buf = Buffer::empty(area);
+            Chart::<String, &str>::default()
+                .x_axis(axis)
+                .y_axis(Axis::default().bounds([0.0, 1.0]).labels(&["0"]))
+                .empty_message("")
+                .draw(&area, &mut buf);
+            buf.to_string().lines().last().unwrap().to_owned()
+        };
+
+        let axis = Axis::default().bounds([0.0, 19.0]).labels(&labels);
+        assert_eq!(draw(axis), " 0 8 16   ");
+        let axis = Axis::default().bounds([0.0, 10.0]).labels(&labels).max_labels(2);
+        assert_eq!(draw(axis), " 0 10     ");
+        let axis = Axis::default().bounds([0.0, 10.0]).labels(&labels).max_labels(1);
+        assert_eq!(draw(axis), " 0        ");
+    }
+
+    #[test]
+    fn named_reference_lines_are_listed_in_the_legend() {
+        let data = [(2.0, 2.0)];
+        let datasets = [Dataset::default().name("data").marker(Marker::Dot).data(&data)];
+        let red = Style::default().fg(Color::Red);
+        let lines = [
+            ReferenceLine::hline(5.0).label("max").style(red),
+            ReferenceLine::vline(5.0),
+        ];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets)
+            .reference_lines(&lines);
+        let area = Rect::new(0, 0, 24, 15);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        let lines = buf.to_string();
+        let lines = lines.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], "           │     ┌─────┐");
+        assert_eq!(lines[1], "           │     │data │");
+        assert_eq!(lines[2], "           │     │─ max│");
+        assert_eq!(lines[3], "           │     └─────┘");
+        assert_eq!(buf.get(18, 2).style.fg, Color::Red);
+
+        let (x, y) = chart.project(5.0, 5.0, &area).unwrap();
+        assert_eq!(lines[y as usize], "───────────│────────────");
+        assert_eq!(buf.get(0, y).style.fg, Color::Red);
+        assert_eq!(buf.get(x, 14).symbol, line::VERTICAL);
+    }
+
+    #[test]
+    fn graph_area_backgrounds_can_fade_between_two_colors() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .empty_message("")
+            .background_gradient(Color::Rgb(0, 100, 200), Color::Rgb(200, 100, 0));
+        let area = Rect::new(0, 0, 4, 5);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.get(0, 0).style.bg, Color::Rgb(0, 100, 200));
+        assert_eq!(buf.get(3, 0).style.bg, Color::Rgb(0, 100, 200));
+        assert_eq!(buf.get(2, 1).style.bg, Color::Rgb(50, 100, 150));
+        assert_eq!(buf.get(1, 2).style.bg, Color::Rgb(100, 100, 100));
+        assert_eq!(buf.get(3, 4).style.bg, Color::Rgb(200, 100, 0));
+
+        // Named colors cannot be interpolated
+        chart.background_gradient(Color::Blue, Color::Rgb(200, 100, 0));
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.get(0, 0).style.bg, Color::Reset);
+        assert_eq!(buf.get(0, 4).style.bg, Color::Reset);
+    }
+
+    #[test]
+    fn missing_bounds_are_computed_from_the_data() {
+        let data = [(1.0, 2.0), (3.0, 10.0), (5.0, 4.0)];
+        let datasets = [Dataset::default().data(&data)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds_with_auto([None, None]))
+            .y_axis(Axis::default().bounds_with_auto([Some(0.0), None]))
+            .datasets(&datasets);
+        assert_eq!(chart.x_axis.bounds, [0.8, 5.2]);
+        assert_eq!(chart.y_axis.bounds, [0.0, 10.5]);
+
+        // The bounds do not depend on the order of the builder calls
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .datasets(&datasets)
+            .y_axis(Axis::default().bounds_with_auto([None, Some(20.0)]));
+        assert_eq!(chart.y_axis.bounds, [1.1, 20.0]);
+
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart.y_axis(Axis::default().bounds_with_auto([Some(1.0), None]));
+        assert_eq!(chart.y_axis.bounds, [1.0, 1.0]);
+    }
+
+    #[test]
+    fn bubbles_grow_with_their_size() {
+        let bubbles = [(0.0, 0.0, 0.0), (5.0, 5.0, 7.0), (10.0, 10.0, 10.0), (0.0, 10.0, 2.0)];
+        let datasets = [Dataset::default().bubbles(&bubbles)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 11, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        let symbol = |x, y| {
+            let (x, y) = chart.project(x, y, &area).unwrap();
+            buf.get(x, y).symbol.clone()
+        };
+        assert_eq!(symbol(0.0, 0.0), symbols::bubble::SIZES[0]);
+        assert_eq!(symbol(0.0, 10.0), symbols::bubble::SIZES[0]);
+        assert_eq!(symbol(5.0, 5.0), symbols::bubble::SIZES[2]);
+        assert_eq!(symbol(10.0, 10.0), symbols::bubble::SIZES[3]);
+    }
+
+    #[test]
+    fn labels_are_hidden_to_keep_a_usable_graph_area() {
+        let mut chart = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["-1000", "1000"]));
+        let area = Rect::new(0, 0, 12, 8);
+        let layout = chart.layout(&area);
+        assert_eq!(layout.graph_area, Rect::new(6, 0, 6, 6));
+
+        chart.min_graph_area(8, 6);
+        let layout = chart.layout(&area);
+        assert_eq!(layout.label_y, None);
+        assert_eq!(layout.axis_y, Some(0));
+        assert!(layout.label_x.is_some());
+        assert_eq!(layout.graph_area, Rect::new(1, 0, 11, 6));
+
+        chart.min_graph_area(8, 7);
+        let layout = chart.layout(&area);
+        assert_eq!((layout.label_x, layout.label_y), (None, None));
+        assert_eq!(layout.graph_area, Rect::new(1, 0, 11, 7));
+
+        chart.min_graph_area(12, 8);
+        let layout = chart.layout(&area);
+        assert_eq!((layout.axis_x, layout.axis_y), (None, None));
+        assert_eq!(layout.graph_area, area);
+    }
+
+    #[test]
+    fn points_in_the_same_cell_set_their_own_dots() {
+        let data = [(0.0, 1.0), (0.25, 0.9)];
+        let datasets = [Dataset::default().marker(Marker::Braille).data(&data)];
+        let mut chart = Chart::<&str, &str>::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 1.0]))
+            .y_axis(Axis::default().bounds([0.0, 1.0]))
+            .datasets(&datasets);
+
+        let dots = chart.braille_dots(&Rect::new(0, 0, 2, 4));
+        assert_eq!(dots[0][0], (DOTS[0][0] | DOTS[1][0]) as u8);
+        assert!(dots.iter().flatten().skip(1).all(|&d| d == 0), "{:?}", dots);
+    }
+
+    #[test]
+    fn colliding_y_labels_are_dropped() {
+        let labels = (0..10).map(|i| format!("y{}", i)).collect::<Vec<String>>();
+        let mut chart: Chart<&str, String> = Chart::default();
+        chart
+            .y_axis(Axis::default().bounds([0.0, 9.0]).labels(&labels))
+            .empty_message("");
+        let area = Rect::new(0, 0, 10, 5);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        let rows = buf.to_string()
+            .lines()
+            .map(|line| line[..2].trim().to_owned())
+            .collect::<Vec<String>>();
+        assert_eq!(rows, vec!["y9", "y7", "y5", "y3", "y0"]);
+    }
+
+    #[test]
+    fn transposed_charts_swap_the_axes() {
+        let data = [(2.0, 7.0), (9.0, 1.0), (4.0, 4.0)];
+        let datasets = [Dataset::default().marker(Marker::Dot).data(&data)];
+        let mut chart = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["x0", "x1"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["y0", "y1"]))
+            .datasets(&datasets);
+        let area = Rect::new(0, 0, 14, 13);
+        let straight = data.iter()
+            .map(|&(x, y)| chart.project(x, y, &area).unwrap())
+            .collect::<Vec<(u16, u16)>>();
+
+        chart.transposed(true);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        for (&(x, y), &(cx, cy)) in data.iter().zip(&straight) {
+            let (tx, ty) = chart.project(x, y, &area).unwrap();
+            // The graph area is square with an origin at (3, 10)
+            assert_eq!((tx - 3, 10 - ty), (10 - cy, cx - 3));
+            assert_eq!(buf.get(tx, ty).symbol, symbols::DOT);
+        }
+        // The labels of the y axis are drawn below the graph area
+        let rows = buf.to_string();
+        assert!(rows.lines().last().unwrap().contains("y1"), "{}", rows);
+        assert!(rows.starts_with("x1"), "{}", rows);
+    }
+
+    #[test]
+    fn selecting_the_left_half_zooms_on_the_lower_x_values() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 100.0]).labels(&["0", "100"]))
+            .y_axis(Axis::default().bounds([-1.0, 1.0]).labels(&["-1", "1"]));
+        let area = Rect::new(0, 0, 24, 12);
+        // The graph area spans the columns 3 to 23 and the rows 0 to 9
+        assert_eq!(chart.project(0.0, -1.0, &area), Some((3, 9)));
+        assert_eq!(chart.project(100.0, 1.0, &area), Some((23, 0)));
+
+        let selection = Rect::new(0, 0, 14, 12);
+        let (x_bounds, y_bounds) = chart.bounds_for_selection(&selection, &area);
+        assert_eq!(x_bounds, [0.0, 50.0]);
+        assert_eq!(y_bounds, [-1.0, 1.0]);
+
+        // Selections outside of the graph area leave the bounds unchanged
+        let selection = Rect::new(0, 10, 24, 2);
+        let (x_bounds, y_bounds) = chart.bounds_for_selection(&selection, &area);
+        assert_eq!(x_bounds, [0.0, 100.0]);
+        assert_eq!(y_bounds, [-1.0, 1.0]);
+    }
+
+    #[test]
+    fn configured_axes_and_datasets_can_be_read_back() {
+        let data = [(0.0, 1.0), (2.0, 3.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .style(Style::default().fg(Color::Yellow));
+        assert_eq!(dataset.get_data(), &data[..]);
+        assert_eq!(dataset.get_color(), Color::Yellow);
+
+        let axis = Axis::default().bounds([-1.0, 1.0]).labels(&["-1", "1"]);
+        assert_eq!(axis.get_bounds(), [-1.0, 1.0]);
+        assert_eq!(axis.get_labels(), Some(&["-1", "1"][..]));
+        assert_eq!(Axis::<&str>::default().get_labels(), None);
+    }
+
+    #[test]
+    fn the_area_between_two_datasets_is_colored_by_the_highest_one() {
+        let a = [(0.0, 8.0), (10.0, 2.0)];
+        let b = [(0.0, 2.0), (10.0, 8.0)];
+        let datasets = [Dataset::default().data(&a), Dataset::default().data(&b)];
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .datasets(&datasets)
+            .fill_between(0, 1, Color::Green, Color::Red);
+        let area = Rect::new(0, 0, 11, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        // A is above B on the left of their crossing and below it on the right
+        assert_eq!(buf.get(1, 5).style.bg, Color::Green);
+        assert_eq!(buf.get(9, 5).style.bg, Color::Red);
+        // Nothing is filled outside of the lines
+        assert_eq!(buf.get(1, 9).style.bg, Color::Reset);
+        assert_eq!(buf.get(9, 1).style.bg, Color::Reset);
+    }
+
+    #[test]
+    fn compact_charts_plot_in_the_whole_inner_area() {
+        let data = [(0.0, 0.0), (5.0, 1.0), (10.0, 0.0)];
+        let datasets = [Dataset::default().name("data").data(&data)];
+        let mut chart = Chart::default();
+        chart
+            .x_axis(Axis::default().title("x").bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 1.0]).labels(&["0", "1"]))
+            .datasets(&datasets)
+            .compact(true);
+        let inner = Rect::new(1, 1, 11, 2);
+        let layout = chart.layout(&inner);
+        assert_eq!(layout.graph_area, inner);
+        assert_eq!((layout.label_x, layout.label_y), (None, None));
+        assert_eq!((layout.axis_x, layout.axis_y), (None, None));
+        assert_eq!(layout.legend_area, None);
+
+        let area = Rect::new(0, 0, 13, 4);
+        let mut buf = Buffer::empty(area);
+        Bordered::new(Block::default().borders(Borders::ALL), chart).draw(&area, &mut buf);
+        assert_eq!(
+            buf.to_string(),
+            "┌───────────┐\n│     •     │\n│•         •│\n└───────────┘"
+        );
+    }
+
+    #[test]
+    fn datasets_with_disjoint_ranges_share_the_x_axis() {
+        let early = [(0.0, 0.0), (4.0, 4.0)];
+        let late = [(6.0, 6.0), (12.0, 5.0)];
+        let mut chart = Chart::<&str, &str>::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]));
+        let area = Rect::new(0, 0, 11, 11);
+
+        let datasets = [
+            Dataset::default().marker(Marker::Custom("a")).data(&early),
+            Dataset::default().marker(Marker::Custom("b")).data(&late),
+        ];
+        chart.datasets(&datasets);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.get(0, 10).symbol, "a");
+        assert_eq!(buf.get(4, 6).symbol, "a");
+        assert_eq!(buf.get(6, 4).symbol, "b");
+        // The point beyond the bounds is dropped
+        assert_eq!(buf.get(10, 5).symbol, " ");
+
+        let datasets = [
+            Dataset::default().marker(Marker::Custom("a")).data(&early),
+            Dataset::default()
+                .marker(Marker::Custom("b"))
+                .data(&late)
+                .clip_to_bounds(true),
+        ];
+        chart.datasets(&datasets);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.get(6, 4).symbol, "b");
+        assert_eq!(buf.get(10, 5).symbol, "b");
+    }
+
+    #[test]
+    fn labels_can_be_kept_away_from_the_axes() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "10"]))
+            .empty_message("");
+        let area = Rect::new(0, 0, 10, 6);
+        let layout = chart.layout(&area);
+        assert_eq!((layout.label_y, layout.axis_y), (Some(0), Some(2)));
+        assert_eq!((layout.label_x, layout.axis_x), (Some(5), Some(4)));
+
+        chart.label_gap(1);
+        let layout = chart.layout(&area);
+        assert_eq!((layout.label_y, layout.axis_y), (Some(0), Some(3)));
+        assert_eq!((layout.label_x, layout.axis_x), (Some(5), Some(3)));
+        assert_eq!(chart.required_left_margin(), 4);
+
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(
+            buf.to_string(),
+            "10 │      \n   │      \n 0 │      \n   └──────\n          \n   0   10 "
+        );
+    }
+
+    #[test]
+    fn nearly_straight_lines_are_simplified_to_their_ends() {
+        let points = [(0.0, 0.0), (1.0, 1.01), (2.0, 1.99), (3.0, 3.02), (4.0, 4.0)];
+        assert_eq!(simplify(&points, 0.1), vec![(0.0, 0.0), (4.0, 4.0)]);
+        // The corners farther than epsilon are kept
+        let points = [(0.0, 0.0), (1.0, 0.05), (2.0, 2.0), (3.0, 0.0), (4.0, 0.0)];
+        assert_eq!(
+            simplify(&points, 0.1),
+            vec![(0.0, 0.0), (1.0, 0.05), (2.0, 2.0), (3.0, 0.0), (4.0, 0.0)]
+        );
+        assert_eq!(
+            simplify(&points, 1.0),
+            vec![(0.0, 0.0), (2.0, 2.0), (4.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn splines_go_through_the_points_along_a_curved_path() {
+        let data = [(0.0, 0.0), (5.0, 10.0), (10.0, 0.0)];
+        let area = Rect::new(0, 0, 11, 11);
+        let draw = |interpolation| {
+            let datasets = [
+                Dataset::default()
+                    .graph_type(GraphType::Line)
+                    .interpolation(interpolation)
+                    .data(&data),
+            ];
+            let mut buf = Buffer::empty(area);
+            Chart::<&str, &str>::default()
+                .x_axis(Axis::default().bounds([0.0, 10.0]))
+                .y_axis(Axis::default().bounds([0.0, 10.0]))
+                .datasets(&datasets)
+                .draw(&area, &mut buf);
+            buf.iter()
+                .filter(|&(_, _, cell)| cell.symbol != " ")
+                .map(|(x, y, _)| (x, y))
+                .collect::<Vec<(u16, u16)>>()
+        };
+
+        let straight = draw(InterpolationMode::Linear);
+        let curved = draw(InterpolationMode::CatmullRom);
+        for point in &[(0, 10), (5, 0), (10, 10)] {
+            assert!(curved.contains(point), "{:?} not in {:?}", point, curved);
+        }
+        // The spline is rounded around the peak instead of going straight to it
+        assert!(!straight.contains(&(3, 0)) && curved.contains(&(3, 0)), "{:?}", curved);
+        assert!(curved.iter().any(|cell| !straight.contains(cell)));
+    }
+
+    #[test]
+    fn y_labels_are_right_aligned_against_the_axis() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .y_axis(Axis::default().bounds([0.0, 100.0]).labels(&["5", "100"]))
+            .empty_message("");
+        let area = Rect::new(0, 0, 8, 3);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "100│    \n   │    \n  5│    ");
+    }
+
+    #[test]
+    fn lines_break_at_missing_points_unless_gaps_are_connected() {
+        let data = [(0.0, 5.0), (5.0, f64::NAN), (10.0, 5.0)];
+        let mut chart = Chart::<&str, &str>::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]));
+        let area = Rect::new(0, 0, 11, 11);
+        let drawn = |buf: &Buffer| {
+            buf.iter()
+                .filter(|&(_, _, cell)| cell.symbol != " ")
+                .map(|(x, y, _)| (x, y))
+                .collect::<Vec<(u16, u16)>>()
+        };
+
+        let datasets = [Dataset::default().graph_type(GraphType::Line).data(&data)];
+        chart.datasets(&datasets);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(drawn(&buf), vec![(0, 5), (10, 5)]);
+
+        let datasets = [
+            Dataset::default()
+                .graph_type(GraphType::Line)
+                .data(&data)
+                .connect_gaps(true),
+        ];
+        chart.datasets(&datasets);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+        assert_eq!(drawn(&buf), (0..11).map(|x| (x, 5)).collect::<Vec<(u16, u16)>>());
+    }
+
+    #[test]
+    fn zero_lines_stand_out_from_the_grid() {
+        let mut chart: Chart<&str, &str> = Chart::default();
+        chart
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(&["0", "5", "10"]))
+            .y_axis(
+                Axis::default()
+                    .bounds([-10.0, 10.0])
+                    .labels(&["-10", "0", "10"])
+                    .zero_line_color(Color::Yellow),
+            )
+            .dot_grid(true)
+            .empty_message("");
+        let area = Rect::new(0, 0, 15, 11);
+        let mut buf = Buffer::empty(area);
+        chart.draw(&area, &mut buf);
+
+        // The graph area spans the rows 0 to 8, the middle one holding the zero
+        for x in 4..15 {
+            assert_eq!(buf.get(x, 4).symbol, line::HORIZONTAL);
+            assert_eq!(buf.get(x, 4).style.fg, Color::Yellow);
+        }
+        assert_eq!(buf.get(4, 0).symbol, symbols::pattern::DOTS);
+        assert_eq!(buf.get(4, 0).style.fg, Color::DarkGray);
+        assert_eq!(buf.get(4, 8).style.fg, Color::DarkGray);
+    }
 }
@@ -3,7 +3,7 @@ use unicode_width::UnicodeWidthStr;
 use widgets::{Block, Widget};
 use buffer::Buffer;
 use style::{Color, Style};
-use layout::Rect;
+use layout::{Alignment, Rect};
 
 /// A widget to display a task progress.
 ///
@@ -24,6 +24,8 @@ pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     percent: u16,
     label: Option<&'a str>,
+    label_alignment: Alignment,
+    segments: Option<u16>,
     style: Style,
 }
 
@@ -33,6 +35,8 @@ impl<'a> Default for Gauge<'a> {
             block: None,
             percent: 0,
             label: None,
+            label_alignment: Alignment::Center,
+            segments: None,
             style: Default::default(),
         }
     }
@@ -49,6 +53,18 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Set the progress as a ratio between 0 and 1 (values outside this range are clamped). The
+    /// ratio is converted to a percentage rounded down, so that `0.999` gives `99%`.
+    pub fn ratio(&mut self, ratio: f64) -> &mut Gauge<'a> {
+        self.percent = (ratio.clamp(0.0, 1.0) * 100.0) as u16;
+        self
+    }
+
+    pub fn label_alignment(&mut self, alignment: Alignment) -> &mut Gauge<'a> {
+        self.label_alignment = alignment;
+        self
+    }
+
     pub fn label(&mut self, string: &'a str) -> &mut Gauge<'a> {
         self.label = Some(string);
         self
@@ -58,6 +74,30 @@ impl<'a> Gauge<'a> {
         self.style = style;
         self
     }
+
+    /// Divide the gauge in the given number of segments separated by a one cell gap. The
+    /// progress is then displayed by lighting up the closest number of whole segments instead of
+    /// filling the gauge continuously.
+    pub fn style_segmented(&mut self, segments: u16) -> &mut Gauge<'a> {
+        self.segments = Some(segments);
+        self
+    }
+
+    /// Returns whether the cell at the given offset from the left of the gauge should be filled
+    fn is_filled(&self, offset: u16, width: u16) -> bool {
+        match self.segments {
+            Some(segments) if segments > 0 => {
+                let (offset, width, segments) =
+                    (u32::from(offset), u32::from(width), u32::from(segments));
+                let segment = offset * segments / width;
+                let lit = (f64::from(self.percent.min(100)) / 100.0 * f64::from(segments))
+                    .round() as u32;
+                let gap = segment + 1 < segments && offset + 1 == (segment + 1) * width / segments;
+                segment < lit && !gap
+            }
+            _ => offset < (width * self.percent) / 100,
+        }
+    }
 }
 
 impl<'a> Widget for Gauge<'a> {
@@ -78,11 +118,12 @@ impl<'a> Widget for Gauge<'a> {
         }
 
         let center = gauge_area.height / 2 + gauge_area.top();
-        let width = (gauge_area.width * self.percent) / 100;
-        let end = gauge_area.left() + width;
+        let filled = (gauge_area.left()..gauge_area.right())
+            .filter(|&x| self.is_filled(x - gauge_area.left(), gauge_area.width))
+            .collect::<Vec<u16>>();
         for y in gauge_area.top()..gauge_area.bottom() {
             // Gauge
-            for x in gauge_area.left()..end {
+            for &x in &filled {
                 buf.get_mut(x, y).set_symbol(" ");
             }
 
@@ -91,12 +132,13 @@ impl<'a> Widget for Gauge<'a> {
                 let precent_label = format!("{}%", self.percent);
                 let label = self.label.unwrap_or(&precent_label);
                 let label_width = label.width() as u16;
-                let middle = (gauge_area.width - label_width) / 2 + gauge_area.left();
-                buf.set_string(middle, y, label, &self.style);
+                let x = gauge_area.left()
+                    + self.label_alignment.offset(label_width, gauge_area.width);
+                buf.set_string(x, y, label, &self.style);
             }
 
             // Fix colors
-            for x in gauge_area.left()..end {
+            for &x in &filled {
                 buf.get_mut(x, y)
                     .set_fg(self.style.bg)
                     .set_bg(self.style.fg);
@@ -104,3 +146,70 @@ impl<'a> Widget for Gauge<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled_cells(buf: &Buffer) -> usize {
+        buf.content()
+            .iter()
+            .filter(|c| c.style.bg == Color::White)
+            .count()
+    }
+
+    #[test]
+    fn ratio_is_converted_to_filled_cells() {
+        let area = Rect::new(0, 0, 10, 1);
+        let style = Style::default().fg(Color::White).bg(Color::Black);
+        for &(ratio, filled, label) in &[
+            (0.5, 5, "50%"),
+            (0.999, 9, "99%"),
+            (1.5, 10, "100%"),
+            (-1.0, 0, "0%"),
+        ] {
+            let mut buf = Buffer::empty(area);
+            Gauge::default()
+                .style(style)
+                .ratio(ratio)
+                .label_alignment(Alignment::Left)
+                .draw(&area, &mut buf);
+            assert_eq!(filled_cells(&buf), filled);
+            assert!(buf.to_string().starts_with(label));
+        }
+    }
+
+    #[test]
+    fn label_is_aligned() {
+        let area = Rect::new(0, 0, 10, 1);
+        for &(alignment, expected) in &[
+            (Alignment::Left, "42%       "),
+            (Alignment::Center, "   42%    "),
+            (Alignment::Right, "       42%"),
+        ] {
+            let mut buf = Buffer::empty(area);
+            Gauge::default()
+                .percent(42)
+                .label_alignment(alignment)
+                .draw(&area, &mut buf);
+            assert_eq!(buf.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn segmented_gauges_light_up_whole_segments() {
+        let area = Rect::new(0, 0, 15, 1);
+        let mut buf = Buffer::empty(area);
+        Gauge::default()
+            .style(Style::default().fg(Color::White))
+            .percent(60)
+            .label("")
+            .style_segmented(5)
+            .draw(&area, &mut buf);
+        let lit = buf.content()
+            .iter()
+            .map(|c| if c.style.bg == Color::White { '#' } else { ' ' })
+            .collect::<String>();
+        assert_eq!(lit, "## ## ##       ");
+    }
+}
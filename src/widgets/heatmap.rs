@@ -0,0 +1,237 @@
+use std::cmp::{max, min};
+
+use unicode_width::UnicodeWidthStr;
+
+use widgets::{Axis, Block, Widget};
+use buffer::Buffer;
+use layout::Rect;
+use style::{Color, Style};
+
+/// A widget to display a grid of intensities as colored cells
+///
+/// Each value of the grid is mapped to a color of the ramp according to its position between the
+/// bounds. Values outside of the bounds are clamped to the ends of the ramp. The first row of the
+/// grid is displayed at the top of the widget. Only the labels of the axes are taken into account,
+/// the first label of each axis being placed in front of the first column (resp. row) of the grid.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::widgets::{Axis, Block, Borders, Heatmap};
+/// # use tui::style::Color;
+/// # fn main() {
+/// Heatmap::default()
+///     .block(Block::default().title("Heatmap").borders(Borders::ALL))
+///     .data(&[&[0.0, 0.5, 1.0], &[1.0, 0.5, 0.0]])
+///     .bounds([0.0, 1.0])
+///     .ramp(vec![Color::Blue, Color::Yellow, Color::Red])
+///     .x_axis(Axis::default().labels(&["A", "B", "C"]))
+///     .y_axis(Axis::default().labels(&["1", "2"]));
+/// # }
+/// ```
+pub struct Heatmap<'a, LX, LY>
+where
+    LX: AsRef<str> + 'a,
+    LY: AsRef<str> + 'a,
+{
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The horizontal axis
+    x_axis: Axis<'a, LX>,
+    /// The vertical axis
+    y_axis: Axis<'a, LY>,
+    /// Rows of values to display
+    data: &'a [&'a [f64]],
+    /// Values mapped to the first and the last color of the ramp
+    bounds: [f64; 2],
+    /// Colors used to represent the values, from the lowest to the highest
+    ramp: Vec<Color>,
+    /// The widget base style
+    style: Style,
+}
+
+impl<'a, LX, LY> Default for Heatmap<'a, LX, LY>
+where
+    LX: AsRef<str>,
+    LY: AsRef<str>,
+{
+    fn default() -> Heatmap<'a, LX, LY> {
+        Heatmap {
+            block: None,
+            x_axis: Axis::default(),
+            y_axis: Axis::default(),
+            data: &[],
+            bounds: [0.0, 1.0],
+            ramp: Vec::new(),
+            style: Default::default(),
+        }
+    }
+}
+
+impl<'a, LX, LY> Heatmap<'a, LX, LY>
+where
+    LX: AsRef<str>,
+    LY: AsRef<str>,
+{
+    pub fn block(&mut self, block: Block<'a>) -> &mut Heatmap<'a, LX, LY> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn x_axis(&mut self, axis: Axis<'a, LX>) -> &mut Heatmap<'a, LX, LY> {
+        self.x_axis = axis;
+        self
+    }
+
+    pub fn y_axis(&mut self, axis: Axis<'a, LY>) -> &mut Heatmap<'a, LX, LY> {
+        self.y_axis = axis;
+        self
+    }
+
+    pub fn data(&mut self, data: &'a [&'a [f64]]) -> &mut Heatmap<'a, LX, LY> {
+        self.data = data;
+        self
+    }
+
+    pub fn bounds(&mut self, bounds: [f64; 2]) -> &mut Heatmap<'a, LX, LY> {
+        self.bounds = bounds;
+        self
+    }
+
+    pub fn ramp(&mut self, ramp: Vec<Color>) -> &mut Heatmap<'a, LX, LY> {
+        self.ramp = ramp;
+        self
+    }
+
+    pub fn style(&mut self, style: Style) -> &mut Heatmap<'a, LX, LY> {
+        self.style = style;
+        self
+    }
+
+    /// Returns the color of the ramp a value is mapped to
+    fn color(&self, value: f64) -> Color {
+        if self.ramp.is_empty() {
+            return self.style.bg;
+        }
+        let ratio = (value - self.bounds[0]) / (self.bounds[1] - self.bounds[0]);
+        let index = (ratio.clamp(0.0, 1.0) * self.ramp.len() as f64) as usize;
+        self.ramp[min(index, self.ramp.len() - 1)]
+    }
+}
+
+impl<'a, LX, LY> Widget for Heatmap<'a, LX, LY>
+where
+    LX: AsRef<str>,
+    LY: AsRef<str>,
+{
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        let heatmap_area = match self.block {
+            Some(ref mut b) => {
+                b.draw(area, buf);
+                b.inner(area)
+            }
+            None => *area,
+        };
+
+        if heatmap_area.width < 1 || heatmap_area.height < 1 {
+            return;
+        }
+
+        self.background(&heatmap_area, buf, self.style.bg);
+
+        let mut grid_area = heatmap_area;
+        let mut label_x = None;
+        if self.x_axis.labels.is_some() && grid_area.height > 1 {
+            grid_area.height -= 1;
+            label_x = Some(grid_area.bottom());
+        }
+        let mut label_y = None;
+        if let Some(labels) = self.y_axis.labels {
+            let width = labels
+                .iter()
+                .fold(0, |acc, l| max(l.as_ref().width(), acc)) as u16;
+            if width + 1 < grid_area.width {
+                label_y = Some(grid_area.left());
+                grid_area.x += width + 1;
+                grid_area.width -= width + 1;
+            }
+        }
+
+        let rows = self.data.len() as u16;
+        let cols = self.data.iter().map(|r| r.len()).max().unwrap_or(0) as u16;
+        if rows == 0 || cols == 0 {
+            return;
+        }
+
+        // Cells of the grid are stretched so that the whole grid fills the available area
+        let col_start = |c: u16| {
+            grid_area.left() + (u32::from(c) * u32::from(grid_area.width) / u32::from(cols)) as u16
+        };
+        let row_start = |r: u16| {
+            grid_area.top() + (u32::from(r) * u32::from(grid_area.height) / u32::from(rows)) as u16
+        };
+
+        for (r, row) in self.data.iter().enumerate() {
+            let r = r as u16;
+            for (c, value) in row.iter().enumerate() {
+                let c = c as u16;
+                let color = self.color(*value);
+                for y in row_start(r)..row_start(r + 1) {
+                    for x in col_start(c)..col_start(c + 1) {
+                        buf.get_mut(x, y).set_symbol(" ").set_bg(color);
+                    }
+                }
+            }
+        }
+
+        if let Some(y) = label_x {
+            let labels = self.x_axis.labels.unwrap();
+            for (c, label) in labels.iter().take(cols as usize).enumerate() {
+                let x = col_start(c as u16);
+                buf.set_stringn(
+                    x,
+                    y,
+                    label.as_ref(),
+                    (col_start(c as u16 + 1) - x) as usize,
+                    &self.x_axis.labels_style,
+                );
+            }
+        }
+
+        if let Some(x) = label_y {
+            let labels = self.y_axis.labels.unwrap();
+            for (r, label) in labels.iter().take(rows as usize).enumerate() {
+                let y = row_start(r as u16);
+                if y < row_start(r as u16 + 1) {
+                    buf.set_string(x, y, label.as_ref(), &self.y_axis.labels_style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_value_maps_to_last_ramp_color() {
+        let mut heatmap: Heatmap<&str, &str> = Heatmap::default();
+        heatmap
+            .data(&[&[0.0, 5.0, 10.0], &[-3.0, 10.0, 42.0]])
+            .bounds([0.0, 10.0])
+            .ramp(vec![Color::Blue, Color::Green, Color::Red]);
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buf = Buffer::empty(area);
+        heatmap.draw(&area, &mut buf);
+
+        assert_eq!(buf.get(0, 0).style.bg, Color::Blue);
+        assert_eq!(buf.get(2, 0).style.bg, Color::Green);
+        assert_eq!(buf.get(4, 0).style.bg, Color::Red);
+        assert_eq!(buf.get(5, 0).style.bg, Color::Red);
+        // Out of range values are clamped
+        assert_eq!(buf.get(0, 1).style.bg, Color::Blue);
+        assert_eq!(buf.get(5, 1).style.bg, Color::Red);
+    }
+}
@@ -0,0 +1,164 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use buffer::Buffer;
+use layout::Rect;
+use style::Style;
+use widgets::{Block, Widget};
+
+/// A widget to edit a single line of text. The text is scrolled horizontally when it does not
+/// fit so that the cursor is always visible.
+///
+/// The widget only displays the text, the application is responsible for updating it and the
+/// cursor (e.g. from the keys read on the terminal) and for moving the terminal cursor to the
+/// position returned by [`Input::cursor_position`](#method.cursor_position).
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::buffer::Buffer;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Block, Borders, Input, Widget};
+/// # fn main() {
+/// let area = Rect::new(0, 0, 9, 3);
+/// let mut buf = Buffer::empty(area);
+/// let mut input = Input::default();
+/// input
+///     .block(Block::default().borders(Borders::ALL))
+///     .value("search")
+///     .cursor(6);
+/// input.draw(&area, &mut buf);
+/// assert_eq!(input.cursor_position(&area), Some((7, 1)));
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Input<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The text being edited
+    value: &'a str,
+    /// Index of the grapheme before which the cursor is placed
+    cursor: usize,
+    /// Widget style
+    style: Style,
+}
+
+impl<'a> Input<'a> {
+    pub fn block(&mut self, block: Block<'a>) -> &mut Input<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn value(&mut self, value: &'a str) -> &mut Input<'a> {
+        self.value = value;
+        self
+    }
+
+    /// Set the position of the cursor as a number of graphemes from the start of the value. The
+    /// cursor is placed after the last grapheme if the index is too large.
+    pub fn cursor(&mut self, index: usize) -> &mut Input<'a> {
+        self.cursor = index;
+        self
+    }
+
+    pub fn style(&mut self, style: Style) -> &mut Input<'a> {
+        self.style = style;
+        self
+    }
+
+    fn inner(&self, area: &Rect) -> Rect {
+        match self.block {
+            Some(ref b) => b.inner(area),
+            None => *area,
+        }
+    }
+
+    /// Returns the number of graphemes hidden on the left of the input and their width, and the
+    /// column of the cursor relatively to the first visible one.
+    fn scroll(&self, width: u16) -> (usize, usize, usize) {
+        let widths = UnicodeSegmentation::graphemes(self.value, true)
+            .map(|g| g.width())
+            .collect::<Vec<usize>>();
+        let cursor = self.cursor.min(widths.len());
+        let column = widths[..cursor].iter().sum::<usize>();
+        // The cell under the cursor has to be visible, be it the one of a wide grapheme or the
+        // blank one after the text
+        let cursor_width = widths.get(cursor).map_or(1, |&w| w.max(1));
+        let (mut hidden, mut hidden_width) = (0, 0);
+        while hidden < cursor && column + cursor_width > hidden_width + width as usize {
+            hidden_width += widths[hidden];
+            hidden += 1;
+        }
+        (hidden, hidden_width, column - hidden_width)
+    }
+
+    /// Returns the cell where the terminal cursor should be placed when the input is drawn in
+    /// the given area, if there is room for it.
+    pub fn cursor_position(&self, area: &Rect) -> Option<(u16, u16)> {
+        let inner = self.inner(area);
+        if inner.width < 1 || inner.height < 1 {
+            return None;
+        }
+        let (_, _, column) = self.scroll(inner.width);
+        Some((inner.left() + column as u16, inner.top()))
+    }
+}
+
+impl<'a> Widget for Input<'a> {
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        let input_area = match self.block {
+            Some(ref mut b) => {
+                b.draw(area, buf);
+                b.inner(area)
+            }
+            None => *area,
+        };
+        if input_area.width < 1 || input_area.height < 1 {
+            return;
+        }
+
+        self.background(&input_area, buf, self.style.bg);
+
+        let (hidden, _, _) = self.scroll(input_area.width);
+        let start = UnicodeSegmentation::grapheme_indices(self.value, true)
+            .nth(hidden)
+            .map_or(self.value.len(), |(i, _)| i);
+        buf.set_stringn(
+            input_area.left(),
+            input_area.top(),
+            &self.value[start..],
+            input_area.width as usize,
+            &self.style,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_values_are_scrolled_to_keep_the_cursor_visible() {
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        let mut input = Input::default();
+        input.value("hello world").cursor(11);
+        input.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "orld ");
+        assert_eq!(input.cursor_position(&area), Some((4, 0)));
+
+        // No scrolling is needed while the cursor fits
+        let mut buf = Buffer::empty(area);
+        input.cursor(2);
+        input.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "hello");
+        assert_eq!(input.cursor_position(&area), Some((2, 0)));
+
+        let mut buf = Buffer::empty(area);
+        input.value("ａｂｃｄ").cursor(2);
+        input.draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "ｂｃ ");
+        assert_eq!(input.cursor_position(&area), Some((2, 0)));
+    }
+}
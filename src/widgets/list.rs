@@ -1,13 +1,16 @@
+use std::borrow::Cow;
 use std::iter;
 use std::fmt::Display;
 use std::iter::Iterator;
 
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use buffer::Buffer;
 use widgets::{Block, Widget};
 use layout::Rect;
 use style::Style;
+use symbols::ELLIPSIS;
 
 pub enum Item<'i, D: 'i> {
     Data(D),
@@ -21,6 +24,10 @@ where
     block: Option<Block<'b>>,
     items: L,
     style: Style,
+    /// Whether the items too long to fit are ended by an ellipsis
+    ellipsis: bool,
+    /// Whether the items too long to fit are continued on the next rows
+    wrapping: bool,
 }
 
 impl<'b, 'i, L, D> Default for List<'b, 'i, L, D>
@@ -32,6 +39,8 @@ where
             block: None,
             items: L::default(),
             style: Default::default(),
+            ellipsis: true,
+            wrapping: false,
         }
     }
 }
@@ -45,6 +54,8 @@ where
             block: None,
             items: items,
             style: Default::default(),
+            ellipsis: true,
+            wrapping: false,
         }
     }
 
@@ -65,6 +76,55 @@ where
         self.style = style;
         self
     }
+
+    /// Cut the items too long to fit in the list abruptly instead of ending them with an ellipsis
+    pub fn no_ellipsis(&'b mut self) -> &mut List<'b, 'i, L, D> {
+        self.ellipsis = false;
+        self
+    }
+
+    /// Wrap the items too long to fit on as many rows as needed. This takes precedence over the
+    /// ellipsis.
+    pub fn wrap(&'b mut self, flag: bool) -> &mut List<'b, 'i, L, D> {
+        self.wrapping = flag;
+        self
+    }
+}
+
+/// Returns the given text shortened to the given width if needed, its end being replaced by an
+/// ellipsis.
+fn ellipsize<'a>(text: &'a str, width: usize) -> Cow<'a, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+    let mut shortened = String::with_capacity(width + ELLIPSIS.len());
+    let mut shortened_width = 0;
+    for g in UnicodeSegmentation::graphemes(text, true) {
+        shortened_width += g.width();
+        if shortened_width + ELLIPSIS.width() > width {
+            break;
+        }
+        shortened.push_str(g);
+    }
+    shortened.push_str(ELLIPSIS);
+    Cow::Owned(shortened)
+}
+
+/// Splits the given text in lines of at most the given width. There is always at least one line.
+fn wrap(text: &str, width: usize) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut line_width = 0;
+    for (i, g) in UnicodeSegmentation::grapheme_indices(text, true) {
+        if line_width + g.width() > width && i > start {
+            lines.push(&text[start..i]);
+            start = i;
+            line_width = 0;
+        }
+        line_width += g.width();
+    }
+    lines.push(&text[start..]);
+    lines
 }
 
 impl<'b, 'i, L, D> Widget for List<'b, 'i, L, D>
@@ -87,35 +147,45 @@ where
 
         self.background(&list_area, buf, self.style.bg);
 
-        for (i, item) in self.items
-            .by_ref()
-            .enumerate()
-            .take(list_area.height as usize)
-        {
-            match item {
-                Item::Data(ref v) => {
-                    buf.set_stringn(
-                        list_area.left(),
-                        list_area.top() + i as u16,
-                        &format!("{}", v),
-                        list_area.width as usize,
-                        &Style::default(),
-                    );
-                }
-                Item::StyledData(ref v, s) => {
-                    buf.set_stringn(
-                        list_area.left(),
-                        list_area.top() + i as u16,
-                        &format!("{}", v),
-                        list_area.width as usize,
-                        s,
-                    );
+        let default_style = Style::default();
+        let width = list_area.width as usize;
+        let mut y = list_area.top();
+        while y < list_area.bottom() {
+            let (text, style) = match self.items.next() {
+                Some(Item::Data(ref v)) => (format!("{}", v), &default_style),
+                Some(Item::StyledData(ref v, s)) => (format!("{}", v), s),
+                None => break,
+            };
+            if self.wrapping {
+                for line in wrap(&text, width).into_iter().take((list_area.bottom() - y) as usize)
+                {
+                    buf.set_stringn(list_area.left(), y, line, width, style);
+                    y += 1;
                 }
+                continue;
+            }
+            let text = if self.ellipsis {
+                ellipsize(&text, width)
+            } else {
+                Cow::Borrowed(text.as_str())
             };
+            buf.set_stringn(list_area.left(), y, &text, width, style);
+            y += 1;
         }
     }
 }
 
+/// How a selectable list is scrolled as the selection moves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollMode {
+    /// The list is scrolled as little as possible to keep the selected item visible
+    KeepVisible,
+    /// The selected item is kept on the middle row, unless it is one of the first items
+    CenterSelected,
+    /// The selected item is kept on the given row, unless it is one of the first items
+    Sticky(u16),
+}
+
 /// A widget to display several items among which one can be selected (optional)
 ///
 /// # Examples
@@ -146,6 +216,10 @@ pub struct SelectableList<'b> {
     highlight_style: Style,
     /// Symbol in front of the selected item (Shift all items to the right)
     highlight_symbol: Option<&'b str>,
+    /// Whether the items too long to fit are continued on the next rows
+    wrapping: bool,
+    /// How the list is scrolled to the selected item
+    scroll_mode: ScrollMode,
 }
 
 impl<'b> Default for SelectableList<'b> {
@@ -157,6 +231,8 @@ impl<'b> Default for SelectableList<'b> {
             style: Default::default(),
             highlight_style: Default::default(),
             highlight_symbol: None,
+            wrapping: false,
+            scroll_mode: ScrollMode::KeepVisible,
         }
     }
 }
@@ -194,6 +270,21 @@ impl<'b> SelectableList<'b> {
         self.selected = Some(index);
         self
     }
+
+    /// Wrap the items too long to fit on as many rows as needed, the highlight style being
+    /// applied to all the rows of the selected item
+    pub fn wrap(&'b mut self, flag: bool) -> &'b mut SelectableList<'b> {
+        self.wrapping = flag;
+        self
+    }
+
+    /// Set how the list is scrolled to the selected item. The selected item is only kept visible
+    /// by default. The rows of the other modes are counted on screen, the wrapped items spanning
+    /// several of them.
+    pub fn scroll_mode(&'b mut self, mode: ScrollMode) -> &'b mut SelectableList<'b> {
+        self.scroll_mode = mode;
+        self
+    }
 }
 
 impl<'b> Widget for SelectableList<'b> {
@@ -215,7 +306,36 @@ impl<'b> Widget for SelectableList<'b> {
             .take(highlight_symbol.width())
             .collect::<String>();
         // Make sure the list show the selected item
-        let offset = if selected >= list_height {
+        let sticky_row = match self.scroll_mode {
+            ScrollMode::KeepVisible => None,
+            ScrollMode::CenterSelected => Some(list_height / 2),
+            ScrollMode::Sticky(row) => Some((row as usize).min(list_height.saturating_sub(1))),
+        };
+        let width = list_area.width as usize;
+        let height = |i: usize| match self.items.get(i) {
+            Some(item) if self.wrapping => wrap(&format!("{} {}", blank_symbol, item), width).len(),
+            _ => 1,
+        };
+        let offset = if let Some(row) = sticky_row {
+            // Go back from the selected item as long as the rows above it fit before the sticky
+            // one, which is moved up if the selected item would not fit below it
+            let row = row.min(list_height.saturating_sub(height(selected)));
+            let mut offset = selected;
+            let mut rows = 0;
+            while offset > 0 && rows + height(offset - 1) <= row {
+                offset -= 1;
+                rows += height(offset);
+            }
+            offset
+        } else if self.wrapping {
+            // Skip items until all the rows of the selected one fit
+            let heights = (0..selected + 1).map(&height).collect::<Vec<usize>>();
+            let mut offset = 0;
+            while offset < selected && heights[offset..].iter().sum::<usize>() > list_height {
+                offset += 1;
+            }
+            offset
+        } else if selected >= list_height {
             selected - list_height + 1
         } else {
             0
@@ -236,6 +356,119 @@ impl<'b> Widget for SelectableList<'b> {
         List::new(items)
             .block(self.block.unwrap_or_default())
             .style(self.style)
+            .wrap(self.wrapping)
             .draw(area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use style::Color;
+
+    #[test]
+    fn long_items_are_ellipsized() {
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buf = Buffer::empty(area);
+        List::new(vec![Item::Data("１２３４"), Item::Data("abcdef")].into_iter())
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "１２… \nabcdef");
+
+        let mut buf = Buffer::empty(area);
+        List::new(vec![Item::Data("abcdefgh")].into_iter())
+            .no_ellipsis()
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "abcdef\n      ");
+
+        let mut buf = Buffer::empty(area);
+        SelectableList::default()
+            .items(&["abcdefgh"])
+            .select(0)
+            .highlight_symbol(">")
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "> abc…\n      ");
+    }
+
+    #[test]
+    fn wrapped_items_span_several_rows() {
+        let area = Rect::new(0, 0, 6, 3);
+        let highlight = Style::default().fg(Color::Red);
+        let mut buf = Buffer::empty(area);
+        SelectableList::default()
+            .items(&["ab", "cdefgh", "ij"])
+            .select(1)
+            .highlight_symbol(">")
+            .highlight_style(highlight)
+            .wrap(true)
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "  ab  \n> cdef\ngh    ");
+        for y in 1..3 {
+            assert_eq!(buf.get(0, y).style.fg, Color::Red);
+        }
+        assert_eq!(buf.get(0, 0).style.fg, Color::Reset);
+
+        // The list is scrolled by whole items to show all the rows of the selected one
+        let mut buf = Buffer::empty(area);
+        SelectableList::default()
+            .items(&["ab", "cd", "efghij"])
+            .select(2)
+            .highlight_symbol(">")
+            .wrap(true)
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "  cd  \n> efgh\nij    ");
+    }
+
+    #[test]
+    fn centered_selections_stay_on_the_middle_row() {
+        let items = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let area = Rect::new(0, 0, 3, 5);
+        let highlighted_row = |selected| {
+            let mut buf = Buffer::empty(area);
+            SelectableList::default()
+                .items(&items)
+                .select(selected)
+                .highlight_symbol(">")
+                .scroll_mode(ScrollMode::CenterSelected)
+                .draw(&area, &mut buf);
+            (0..5).find(|&y| buf.get(0, y).symbol == ">").unwrap()
+        };
+        assert_eq!(highlighted_row(0), 0);
+        assert_eq!(highlighted_row(1), 1);
+        for selected in 2..10 {
+            assert_eq!(highlighted_row(selected), 2);
+        }
+
+        let mut buf = Buffer::empty(area);
+        SelectableList::default()
+            .items(&items)
+            .select(6)
+            .scroll_mode(ScrollMode::Sticky(1))
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), " 5 \n 6 \n 7 \n 8 \n 9 ");
+    }
+
+    #[test]
+    fn sticky_rows_are_counted_on_screen_with_wrapped_items() {
+        let area = Rect::new(0, 0, 4, 5);
+        let mut buf = Buffer::empty(area);
+        SelectableList::default()
+            .items(&["0", "1", "23456", "7", "8", "9"])
+            .select(3)
+            .highlight_symbol(">")
+            .wrap(true)
+            .scroll_mode(ScrollMode::CenterSelected)
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "  23\n456 \n> 7 \n  8 \n  9 ");
+
+        // The selected item is moved up to show all its rows
+        let mut buf = Buffer::empty(area);
+        SelectableList::default()
+            .items(&["0", "1", "2", "3", "45678901"])
+            .select(4)
+            .highlight_symbol(">")
+            .wrap(true)
+            .scroll_mode(ScrollMode::Sticky(3))
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "  2 \n  3 \n> 45\n6789\n01  ");
+    }
+}
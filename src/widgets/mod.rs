@@ -1,4 +1,5 @@
 mod block;
+mod bordered;
 mod paragraph;
 mod list;
 mod gauge;
@@ -7,17 +8,27 @@ mod chart;
 mod barchart;
 mod tabs;
 mod table;
+mod heatmap;
+mod input;
+mod projection;
+mod scrollbar;
 pub mod canvas;
 
-pub use self::block::Block;
+pub use self::block::{Block, BorderType};
+pub use self::bordered::Bordered;
 pub use self::paragraph::Paragraph;
-pub use self::list::{Item, List, SelectableList};
+pub use self::list::{Item, List, ScrollMode, SelectableList};
 pub use self::gauge::Gauge;
 pub use self::sparkline::Sparkline;
-pub use self::chart::{Axis, Chart, Dataset, Marker};
+pub use self::chart::{Axis, Chart, ClipMode, Dataset, GraphType, InterpolationMode, Marker,
+                      NumberFormat, Pattern, ReferenceLine, RingDataset, Rotation, SharedXAxis};
 pub use self::barchart::BarChart;
 pub use self::tabs::Tabs;
-pub use self::table::{Row, Table};
+pub use self::table::{Row, Table, TableState};
+pub use self::heatmap::Heatmap;
+pub use self::input::Input;
+pub use self::projection::{Projection, Scale};
+pub use self::scrollbar::Scrollbar;
 
 use buffer::Buffer;
 use layout::Rect;
@@ -44,6 +55,29 @@ bitflags! {
 }
 
 /// Base requirements for a Widget
+///
+/// The trait is object safe so that widgets of different types may be stored together and drawn
+/// through trait objects:
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::buffer::Buffer;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Block, Borders, Gauge, Widget};
+/// # fn main() {
+/// let mut gauge = Gauge::default();
+/// gauge.percent(50);
+/// let mut widgets: Vec<Box<dyn Widget>> = vec![
+///     Box::new(Block::default().borders(Borders::ALL)),
+///     Box::new(gauge),
+/// ];
+/// let area = Rect::new(0, 0, 10, 10);
+/// let mut buf = Buffer::empty(area);
+/// for widget in &mut widgets {
+///     widget.draw(&area, &mut buf);
+/// }
+/// # }
+/// ```
 pub trait Widget {
     /// Draws the current state of the widget in the given buffer. That the only method required to
     /// implement a custom widget.
@@ -65,3 +99,59 @@ pub trait Widget {
         t.render(self, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use layout::Alignment;
+
+    #[test]
+    fn boxed_widgets_can_be_drawn_together() {
+        let mut gauge = Gauge::default();
+        gauge.percent(100).label("ok");
+        let mut paragraph = Paragraph::default();
+        paragraph.text("text");
+        let mut widgets: Vec<(Rect, Box<dyn Widget>)> = vec![
+            (
+                Rect::new(0, 0, 10, 3),
+                Box::new(Block::default().borders(Borders::ALL)),
+            ),
+            (Rect::new(0, 3, 10, 1), Box::new(gauge)),
+            (Rect::new(0, 4, 10, 1), Box::new(paragraph)),
+        ];
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        for &mut (ref area, ref mut widget) in &mut widgets {
+            widget.draw(area, &mut buf);
+        }
+
+        assert_eq!(
+            buf.to_string(),
+            "┌────────┐\n│        │\n└────────┘\n    ok    \ntext      "
+        );
+    }
+
+    #[test]
+    fn widgets_share_the_layout_alignment() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Block::default()
+            .title("ab")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .draw(&Rect::new(0, 0, 12, 2), &mut buf);
+        Tabs::default()
+            .titles(&["ab"])
+            .alignment(Alignment::Right)
+            .draw(&Rect::new(0, 2, 12, 1), &mut buf);
+        Gauge::default()
+            .percent(0)
+            .label("ab")
+            .label_alignment(Alignment::Left)
+            .draw(&Rect::new(0, 3, 12, 1), &mut buf);
+
+        assert_eq!(
+            buf.to_string(),
+            "┌────ab────┐\n└──────────┘\n         ab \nab          "
+        );
+    }
+}
@@ -3,11 +3,13 @@ use unicode_width::UnicodeWidthStr;
 
 use widgets::{Block, Widget};
 use buffer::Buffer;
-use layout::Rect;
+use layout::{Rect, TextDirection};
 use style::{Color, Modifier, Style};
 
 /// A widget to display some text. You can specify colors using commands embedded in
-/// the text such as "{[color] [text]}".
+/// the text such as "{[color] [text]}". The `link=[url]` command makes the text a hyperlink on
+/// the terminals supporting them. It has to come last since the URL runs until the end of the
+/// command, `;`, `{` and `}` included. Only the spaces of the URL have to be percent-encoded.
 ///
 /// # Examples
 ///
@@ -20,7 +22,7 @@ use style::{Color, Modifier, Style};
 ///     .block(Block::default().title("Paragraph").borders(Borders::ALL))
 ///     .style(Style::default().fg(Color::White).bg(Color::Black))
 ///     .wrap(true)
-///     .text("First line\nSecond line\n{red Colored text}.");
+///     .text("First line\nSecond line\n{red Colored text}.\n{link=https://docs.rs Docs}");
 /// # }
 /// ```
 pub struct Paragraph<'a> {
@@ -36,6 +38,8 @@ pub struct Paragraph<'a> {
     raw: bool,
     /// Scroll
     scroll: u16,
+    /// Direction in which the lines are laid out
+    direction: TextDirection,
 }
 
 impl<'a> Default for Paragraph<'a> {
@@ -47,6 +51,7 @@ impl<'a> Default for Paragraph<'a> {
             raw: false,
             text: "",
             scroll: 0,
+            direction: TextDirection::LeftToRight,
         }
     }
 }
@@ -81,6 +86,14 @@ impl<'a> Paragraph<'a> {
         self.scroll = offset;
         self
     }
+
+    /// Lay the lines out from the right edge of the area, their graphemes being drawn in
+    /// reversed order, which is enough to display right-to-left text that does not mix
+    /// directions.
+    pub fn direction(&mut self, direction: TextDirection) -> &mut Paragraph<'a> {
+        self.direction = direction;
+        self
+    }
 }
 
 struct Parser<'a, T>
@@ -92,6 +105,7 @@ where
     cmd_string: String,
     style: Style,
     base_style: Style,
+    link: Option<String>,
     escaping: bool,
     styling: bool,
 }
@@ -107,14 +121,32 @@ where
             cmd_string: String::from(""),
             style: base_style,
             base_style: base_style,
+            link: None,
             escaping: false,
             styling: false,
         }
     }
 
+    /// Returns the offset of the URL in the command being parsed, if it holds a link command
+    fn link_offset(&self) -> Option<usize> {
+        let cmd = &self.cmd_string;
+        cmd.match_indices("link=")
+            .find(|&(i, _)| i == 0 || cmd[..i].ends_with(';'))
+            .map(|(i, _)| i + "link=".len())
+    }
+
     fn update_style(&mut self) {
-        for cmd in self.cmd_string.split(';') {
-            let args = cmd.split('=').collect::<Vec<&str>>();
+        // The URL of the link is the rest of the command, whatever characters it holds
+        let cmd = self.cmd_string.clone();
+        let styles = match self.link_offset() {
+            Some(offset) => {
+                self.link = Some(String::from(&cmd[offset..]));
+                &cmd[..offset - "link=".len()]
+            }
+            None => &cmd[..],
+        };
+        for cmd in styles.split(';') {
+            let args = cmd.splitn(2, '=').collect::<Vec<&str>>();
             if let Some(first) = args.get(0) {
                 match *first {
                     "fg" => if let Some(snd) = args.get(1) {
@@ -169,6 +201,7 @@ where
         self.styling = false;
         self.mark = false;
         self.style = self.base_style;
+        self.link = None;
         self.cmd_string.clear();
     }
 }
@@ -177,12 +210,12 @@ impl<'a, T> Iterator for Parser<'a, T>
 where
     T: Iterator<Item = &'a str>,
 {
-    type Item = (&'a str, Style);
+    type Item = (&'a str, Style, Option<String>);
     fn next(&mut self) -> Option<Self::Item> {
         match self.text.next() {
             Some(s) => if s == "\\" {
                 if self.escaping {
-                    Some((s, self.style))
+                    Some((s, self.style, self.link.clone()))
                 } else {
                     self.escaping = true;
                     self.next()
@@ -190,20 +223,23 @@ where
             } else if s == "{" {
                 if self.escaping {
                     self.escaping = false;
-                    Some((s, self.style))
+                    Some((s, self.style, self.link.clone()))
+                } else if self.mark && !self.styling {
+                    self.cmd_string.push_str(s);
+                    self.next()
                 } else if self.mark {
-                    Some((s, self.style))
+                    Some((s, self.style, self.link.clone()))
                 } else {
                     self.style = self.base_style;
                     self.mark = true;
                     self.next()
                 }
-            } else if s == "}" && self.mark {
+            } else if s == "}" && self.mark && (self.styling || self.link_offset().is_none()) {
                 self.reset();
                 self.next()
             } else if s == " " && self.mark {
                 if self.styling {
-                    Some((s, self.style))
+                    Some((s, self.style, self.link.clone()))
                 } else {
                     self.styling = true;
                     self.update_style();
@@ -213,7 +249,7 @@ where
                 self.cmd_string.push_str(s);
                 self.next()
             } else {
-                Some((s, self.style))
+                Some((s, self.style, self.link.clone()))
             },
             None => None,
         }
@@ -239,14 +275,14 @@ impl<'a> Widget for Paragraph<'a> {
         let mut x = 0;
         let mut y = 0;
         let graphemes = UnicodeSegmentation::graphemes(self.text, true);
-        let styled: Box<Iterator<Item = (&str, Style)>> = if self.raw {
-            Box::new(graphemes.map(|g| (g, self.style)))
+        let styled: Box<Iterator<Item = (&str, Style, Option<String>)>> = if self.raw {
+            Box::new(graphemes.map(|g| (g, self.style, None)))
         } else {
             Box::new(Parser::new(graphemes, self.style))
         };
 
         let mut remove_leading_whitespaces = false;
-        for (string, style) in styled {
+        for (string, style, link) in styled {
             if string == "\n" {
                 x = 0;
                 y += 1;
@@ -273,10 +309,56 @@ impl<'a> Widget for Paragraph<'a> {
                 continue;
             }
 
-            buf.get_mut(text_area.left() + x, text_area.top() + y - self.scroll)
+            let width = string.width() as u16;
+            let column = match self.direction {
+                TextDirection::LeftToRight => x,
+                TextDirection::RightToLeft => {
+                    if x + width.max(1) > text_area.width {
+                        x += width;
+                        continue;
+                    }
+                    text_area.width - x - width.max(1)
+                }
+            };
+            buf.get_mut(text_area.left() + column, text_area.top() + y - self.scroll)
                 .set_symbol(string)
-                .set_style(style);
-            x += string.width() as u16;
+                .set_style(style)
+                .set_link(link.as_deref());
+            x += width;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn right_to_left_lines_start_from_the_right_edge() {
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buf = Buffer::empty(area);
+        Paragraph::default()
+            .direction(TextDirection::RightToLeft)
+            .wrap(true)
+            .text("שלום\nעולם טוב")
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "  םולש\nט םלוע\n    בו");
+    }
+
+    #[test]
+    fn link_commands_attach_their_url_to_the_text() {
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buf = Buffer::empty(area);
+        Paragraph::default()
+            .text("see {fg=blue;link=https://a.b/?q=1;r={x} docs}.")
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "see docs.   ");
+        let links = (0..6)
+            .map(|x| buf.get(x, 0).link.as_ref().map(|l| l.as_str()))
+            .collect::<Vec<_>>();
+        let url = Some("https://a.b/?q=1;r={x}");
+        assert_eq!(links, vec![None, None, None, None, url, url]);
+        assert_eq!(buf.get(7, 0).style.fg, Color::Blue);
+        assert_eq!(buf.get(8, 0).link, None);
+    }
+}
@@ -0,0 +1,205 @@
+/// How values are distributed along an axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    /// Values are evenly distributed
+    Linear,
+    /// The logarithms of the values are evenly distributed (only positive values can be
+    /// projected)
+    Log,
+    /// Symmetric logarithm: the values are evenly distributed between `-linthresh` and
+    /// `linthresh` and their logarithms beyond, so that data crossing zero and spanning several
+    /// orders of magnitude can be projected. A threshold smaller than `f64::EPSILON`, or NaN, is
+    /// replaced by `f64::EPSILON`.
+    SymLog { linthresh: f64 },
+}
+
+/// Returns the threshold of a symmetric logarithm, clamped to a positive value
+fn positive(linthresh: f64) -> f64 {
+    if linthresh > f64::EPSILON {
+        linthresh
+    } else {
+        f64::EPSILON
+    }
+}
+
+impl Scale {
+    fn apply(&self, value: f64) -> f64 {
+        match *self {
+            Scale::Linear => value,
+            Scale::Log => value.ln(),
+            Scale::SymLog { linthresh } => {
+                let value = value / positive(linthresh);
+                if value.abs() <= 1.0 {
+                    value
+                } else {
+                    value.signum() * (1.0 + value.abs().ln())
+                }
+            }
+        }
+    }
+
+    fn invert(&self, value: f64) -> f64 {
+        match *self {
+            Scale::Linear => value,
+            Scale::Log => value.exp(),
+            Scale::SymLog { linthresh } => {
+                let linthresh = positive(linthresh);
+                if value.abs() <= 1.0 {
+                    value * linthresh
+                } else {
+                    value.signum() * (value.abs() - 1.0).exp() * linthresh
+                }
+            }
+        }
+    }
+}
+
+/// Maps the values lying between some bounds to the cells of a row or a column of the screen and
+/// back.
+///
+/// The lower bound is projected to the first cell and the upper bound to the last one, unless
+/// the projection is reversed (e.g. on a vertical axis where the values grow upward while the
+/// rows are numbered downward).
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::Projection;
+/// // A vertical axis going from 0 to 100 drawn from the row 2 to the row 12
+/// let projection = Projection::new([0.0, 100.0], 2, 11).reversed(true);
+/// assert_eq!(projection.forward(0.0), 12.0);
+/// assert_eq!(projection.forward(50.0), 7.0);
+/// assert_eq!(projection.inverse(2.0), 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projection {
+    bounds: [f64; 2],
+    start: u16,
+    length: u16,
+    scale: Scale,
+    reversed: bool,
+}
+
+impl Projection {
+    /// Creates a linear projection of the values between the given bounds to the `length` cells
+    /// following (and including) the `start` cell
+    pub fn new(bounds: [f64; 2], start: u16, length: u16) -> Projection {
+        Projection {
+            bounds,
+            start,
+            length,
+            scale: Scale::Linear,
+            reversed: false,
+        }
+    }
+
+    pub fn scale(mut self, scale: Scale) -> Projection {
+        self.scale = scale;
+        self
+    }
+
+    pub fn reversed(mut self, reversed: bool) -> Projection {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Returns the position of the given value in cells. The integer part of the result is the
+    /// cell the value falls in.
+    pub fn forward(&self, value: f64) -> f32 {
+        let lower = self.scale.apply(self.bounds[0]);
+        let upper = self.scale.apply(self.bounds[1]);
+        let value = self.scale.apply(value);
+        let t = if self.reversed {
+            (upper - value) / (upper - lower)
+        } else {
+            (value - lower) / (upper - lower)
+        };
+        (f64::from(self.start) + t * f64::from(self.length.saturating_sub(1))) as f32
+    }
+
+    /// Returns the value projected to the given position in cells
+    pub fn inverse(&self, cell: f32) -> f64 {
+        let lower = self.scale.apply(self.bounds[0]);
+        let upper = self.scale.apply(self.bounds[1]);
+        let t = (f64::from(cell) - f64::from(self.start))
+            / f64::from(self.length.saturating_sub(1));
+        let value = if self.reversed {
+            upper - t * (upper - lower)
+        } else {
+            lower + t * (upper - lower)
+        };
+        self.scale.invert(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip(projection: &Projection, values: &[f64]) {
+        for &value in values {
+            let result = projection.inverse(projection.forward(value));
+            assert!(
+                (result - value).abs() <= value.abs().max(1.0) * 1e-6,
+                "{:?}: {} != {}",
+                projection,
+                result,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn projections_can_be_inverted() {
+        let values = [-10.0, -3.5, 0.0, 1.0, 7.25, 10.0];
+        let projection = Projection::new([-10.0, 10.0], 3, 21);
+        assert_eq!(projection.forward(-10.0), 3.0);
+        assert_eq!(projection.forward(0.0), 13.0);
+        assert_eq!(projection.forward(10.0), 23.0);
+        assert_round_trip(&projection, &values);
+
+        let projection = projection.reversed(true);
+        assert_eq!(projection.forward(-10.0), 23.0);
+        assert_eq!(projection.forward(10.0), 3.0);
+        assert_round_trip(&projection, &values);
+
+        let values = [1.0, 5.0, 10.0, 999.0, 1000.0];
+        let projection = Projection::new([1.0, 1000.0], 0, 4).scale(Scale::Log);
+        assert!((projection.forward(10.0) - 1.0).abs() < 1e-6);
+        assert!((projection.forward(100.0) - 2.0).abs() < 1e-6);
+        assert_round_trip(&projection, &values);
+        assert_round_trip(&projection.reversed(true), &values);
+    }
+
+    #[test]
+    fn symlog_projections_are_continuous_around_zero() {
+        let scale = Scale::SymLog { linthresh: 1.0 };
+        let projection = Projection::new([-1000.0, 1000.0], 0, 81).scale(scale);
+        assert_eq!(projection.forward(0.0), 40.0);
+        assert_eq!(projection.forward(-1000.0), 0.0);
+        assert_eq!(projection.forward(1000.0), 80.0);
+
+        // Nearby values stay close to each other, including on each side of the threshold
+        let values = [-2.0, -1.0 - 1e-9, -1.0, -0.5, -1e-9, 0.0, 1e-9, 0.5, 1.0, 1.0 + 1e-9, 2.0];
+        for pair in values.windows(2) {
+            let (a, b) = (projection.forward(pair[0]), projection.forward(pair[1]));
+            assert!(a <= b && b - a <= 4.0, "{} -> {}, {} -> {}", pair[0], a, pair[1], b);
+        }
+        assert_round_trip(&projection, &values);
+        assert_round_trip(&projection, &[-1000.0, -42.0, 42.0, 1000.0]);
+    }
+
+    #[test]
+    fn symlog_thresholds_are_kept_positive() {
+        for &linthresh in &[0.0, -1.0, ::std::f64::NAN] {
+            let scale = Scale::SymLog { linthresh };
+            let projection = Projection::new([-1000.0, 1000.0], 0, 81).scale(scale);
+            assert_eq!(projection.forward(0.0), 40.0);
+            assert_eq!(projection.forward(1000.0), 80.0);
+            let values = [-1000.0, -1.0, 0.0, 0.5, 42.0, 1000.0];
+            let cells = values.iter().map(|&v| projection.forward(v)).collect::<Vec<f32>>();
+            assert!(cells.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", cells);
+            assert_round_trip(&projection, &[-1000.0, 0.0, 1000.0]);
+        }
+    }
+}
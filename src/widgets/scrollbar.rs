@@ -0,0 +1,178 @@
+use buffer::Buffer;
+use layout::{Direction, Rect};
+use style::Style;
+use symbols::scrollbar;
+use widgets::Widget;
+
+/// A widget showing which part of some scrollable content is visible. It is meant to be drawn in
+/// a one cell wide (or high) area next to the content.
+///
+/// The thumb length is proportional to the part of the content that is visible and its position
+/// to the offset of this part within the content.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate tui;
+/// # use tui::buffer::Buffer;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Scrollbar, Widget};
+/// # fn main() {
+/// let area = Rect::new(0, 0, 1, 4);
+/// let mut buf = Buffer::empty(area);
+/// Scrollbar::default()
+///     .content_length(8)
+///     .viewport_length(4)
+///     .offset(4)
+///     .draw(&area, &mut buf);
+/// assert_eq!(buf.to_string(), "░\n░\n█\n█");
+/// # }
+/// ```
+pub struct Scrollbar {
+    /// Whether the track is vertical or horizontal
+    direction: Direction,
+    /// Length of the whole content
+    content_length: usize,
+    /// Length of the visible part of the content
+    viewport_length: usize,
+    /// Offset of the visible part from the start of the content
+    offset: usize,
+    /// Whether arrows are drawn at both ends of the track
+    arrows: bool,
+    /// Style of the track and of the arrows
+    style: Style,
+    /// Style of the thumb
+    thumb_style: Style,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Scrollbar {
+        Scrollbar {
+            direction: Direction::Vertical,
+            content_length: 0,
+            viewport_length: 0,
+            offset: 0,
+            arrows: false,
+            style: Default::default(),
+            thumb_style: Default::default(),
+        }
+    }
+}
+
+impl Scrollbar {
+    pub fn direction(&mut self, direction: Direction) -> &mut Scrollbar {
+        self.direction = direction;
+        self
+    }
+
+    pub fn content_length(&mut self, length: usize) -> &mut Scrollbar {
+        self.content_length = length;
+        self
+    }
+
+    pub fn viewport_length(&mut self, length: usize) -> &mut Scrollbar {
+        self.viewport_length = length;
+        self
+    }
+
+    pub fn offset(&mut self, offset: usize) -> &mut Scrollbar {
+        self.offset = offset;
+        self
+    }
+
+    /// Draw arrows at the beginning and at the end of the track
+    pub fn arrows(&mut self, arrows: bool) -> &mut Scrollbar {
+        self.arrows = arrows;
+        self
+    }
+
+    pub fn style(&mut self, style: Style) -> &mut Scrollbar {
+        self.style = style;
+        self
+    }
+
+    pub fn thumb_style(&mut self, style: Style) -> &mut Scrollbar {
+        self.thumb_style = style;
+        self
+    }
+
+    /// Returns the offset of the thumb from the start of a track of the given length and its
+    /// length
+    fn thumb(&self, track_length: u16) -> (u16, u16) {
+        if self.content_length <= self.viewport_length {
+            return (0, track_length);
+        }
+        let track = track_length as f64;
+        let length = (track * self.viewport_length as f64 / self.content_length as f64)
+            .round()
+            .max(1.0)
+            .min(track);
+        let max_offset = (self.content_length - self.viewport_length) as f64;
+        let offset = self.offset.min(self.content_length - self.viewport_length) as f64;
+        let start = ((track - length) * offset / max_offset).round();
+        (start as u16, length as u16)
+    }
+}
+
+impl Widget for Scrollbar {
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        let (length, begin, end) = match self.direction {
+            Direction::Vertical => (area.height, scrollbar::UP, scrollbar::DOWN),
+            Direction::Horizontal => (area.width, scrollbar::LEFT, scrollbar::RIGHT),
+        };
+        if area.width < 1 || area.height < 1 {
+            return;
+        }
+        let arrows = self.arrows && length > 2;
+        let track_length = if arrows { length - 2 } else { length };
+        let (thumb_start, thumb_length) = self.thumb(track_length);
+
+        for i in 0..length {
+            let (symbol, style) = if arrows && i == 0 {
+                (begin, self.style)
+            } else if arrows && i == length - 1 {
+                (end, self.style)
+            } else {
+                let position = if arrows { i - 1 } else { i };
+                if position >= thumb_start && position < thumb_start + thumb_length {
+                    (scrollbar::THUMB, self.thumb_style)
+                } else {
+                    (scrollbar::TRACK, self.style)
+                }
+            };
+            let (x, y) = match self.direction {
+                Direction::Vertical => (area.left(), area.top() + i),
+                Direction::Horizontal => (area.left() + i, area.top()),
+            };
+            buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumb_is_proportional_to_the_viewport() {
+        let mut scrollbar = Scrollbar::default();
+        scrollbar
+            .content_length(100)
+            .viewport_length(10)
+            .offset(40);
+        assert_eq!(scrollbar.thumb(10), (4, 1));
+        assert_eq!(scrollbar.thumb(20), (8, 2));
+
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buf = Buffer::empty(area);
+        scrollbar
+            .direction(Direction::Horizontal)
+            .arrows(true)
+            .draw(&area, &mut buf);
+        assert_eq!(buf.to_string(), "◀░░░░█░░░░░▶");
+
+        // The thumb fills the track when the whole content is visible
+        scrollbar.content_length(5);
+        assert_eq!(scrollbar.thumb(10), (0, 10));
+    }
+}
@@ -3,7 +3,7 @@ use std::iter::Iterator;
 
 use buffer::Buffer;
 use widgets::{Block, Widget};
-use layout::Rect;
+use layout::{Alignment, Rect};
 use style::Style;
 
 /// Holds data to be displayed in a Table widget
@@ -16,6 +16,95 @@ where
     StyledData(D, &'i Style),
 }
 
+/// The selected row and column of a table and the offsets from which its rows and columns are
+/// displayed. The offsets are updated each time the table is drawn so that the selection stays
+/// visible, hence the state has to be kept by the application between frames.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::TableState;
+/// let mut state = TableState::default();
+/// state.select_next_row(10);
+/// state.select_next_col(3);
+/// state.select_next_col(3);
+/// assert_eq!((state.selected_row, state.selected_col), (Some(0), Some(1)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TableState {
+    pub selected_row: Option<usize>,
+    pub selected_col: Option<usize>,
+    /// Index of the first row displayed
+    pub row_offset: usize,
+    /// Index of the first column displayed
+    pub col_offset: usize,
+}
+
+impl TableState {
+    /// Select the row below the selected one (the first one if none is), among `rows` rows
+    pub fn select_next_row(&mut self, rows: usize) {
+        self.selected_row = next(self.selected_row, rows);
+    }
+
+    /// Select the row above the selected one (the first one if none is)
+    pub fn select_previous_row(&mut self) {
+        self.selected_row = Some(self.selected_row.map_or(0, |i| i.saturating_sub(1)));
+    }
+
+    /// Select the column on the right of the selected one (the first one if none is), among
+    /// `cols` columns
+    pub fn select_next_col(&mut self, cols: usize) {
+        self.selected_col = next(self.selected_col, cols);
+    }
+
+    /// Select the column on the left of the selected one (the first one if none is)
+    pub fn select_previous_col(&mut self) {
+        self.selected_col = Some(self.selected_col.map_or(0, |i| i.saturating_sub(1)));
+    }
+
+    /// Moves the offsets so that the selected row and column are among the displayed ones
+    fn scroll_to_selection(&mut self, visible_rows: usize, widths: &[u16], available: u16) {
+        if let Some(row) = self.selected_row {
+            if row < self.row_offset {
+                self.row_offset = row;
+            } else if visible_rows > 0 && row >= self.row_offset + visible_rows {
+                self.row_offset = row + 1 - visible_rows;
+            }
+        }
+        if let Some(col) = self.selected_col {
+            let col = col.min(widths.len().saturating_sub(1));
+            if col < self.col_offset {
+                self.col_offset = col;
+            }
+            while self.col_offset < col
+                && self.col_offset + fitting_columns(&widths[self.col_offset..], available) <= col
+            {
+                self.col_offset += 1;
+            }
+        }
+    }
+}
+
+fn next(selected: Option<usize>, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(selected.map_or(0, |i| (i + 1).min(len - 1)))
+}
+
+/// Returns the number of columns of the given widths that fit in the available width
+fn fitting_columns(widths: &[u16], available: u16) -> usize {
+    let mut x = 0;
+    widths
+        .iter()
+        .take_while(|&&width| {
+            let fits = x + width < available;
+            x += width;
+            fits
+        })
+        .count()
+}
+
 /// A widget to display data in formatted columns
 ///
 /// # Examples
@@ -41,6 +130,32 @@ where
 ///     .column_spacing(1);
 /// # }
 /// ```
+///
+/// The rows are pulled lazily from the given iterator and only the displayed ones are formatted:
+/// those below the displayed window are never pulled, so that huge or even endless data sets can
+/// be displayed without being materialized. The rows above the offset of the state are skipped
+/// with `Iterator::nth`, they are therefore still pulled, and built, one by one unless the
+/// iterator can seek (such as a range or a slice iterator, but not the adaptors like `map`
+/// built on top of them).
+///
+/// ```
+/// # use tui::buffer::Buffer;
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Row, Table, TableState, Widget};
+/// # fn main() {
+/// let mut state = TableState::default();
+/// state.selected_row = Some(1_000_000);
+/// let area = Rect::new(0, 0, 20, 10);
+/// let mut buf = Buffer::empty(area);
+/// Table::new(
+///     ["n", "n²"].iter(),
+///     (0u64..).map(|n| Row::Data(vec![n, n * n].into_iter())),
+/// ).widths(&[8, 8])
+///     .state(&mut state)
+///     .draw(&area, &mut buf);
+/// assert_eq!(state.row_offset, 999_993);
+/// # }
+/// ```
 pub struct Table<'a, 'i, T, H, I, D, R>
 where
     T: Display,
@@ -62,8 +177,14 @@ where
     widths: &'a [u16],
     /// Space between each column
     column_spacing: u16,
+    /// Alignment of the content of each column (left by default)
+    alignments: &'a [Alignment],
     /// Data to display in each row
     rows: R,
+    /// Selection and scrolling of the table
+    state: Option<&'a mut TableState>,
+    /// Style used to render the selected cells
+    highlight_style: Style,
 }
 
 impl<'a, 'i, T, H, I, D, R> Default for Table<'a, 'i, T, H, I, D, R>
@@ -83,6 +204,9 @@ where
             widths: &[],
             rows: R::default(),
             column_spacing: 1,
+            alignments: &[],
+            state: None,
+            highlight_style: Style::default(),
         }
     }
 }
@@ -104,6 +228,9 @@ where
             widths: &[],
             rows: rows,
             column_spacing: 1,
+            alignments: &[],
+            state: None,
+            highlight_style: Style::default(),
         }
     }
     pub fn block(&'a mut self, block: Block<'a>) -> &mut Table<'a, 'i, T, H, I, D, R> {
@@ -146,6 +273,26 @@ where
         self.column_spacing = spacing;
         self
     }
+
+    /// Set the alignment of the content of each column within its width, e.g. to right-align
+    /// numbers. The columns without an alignment are left-aligned.
+    pub fn alignments(&mut self, alignments: &'a [Alignment]) -> &mut Table<'a, 'i, T, H, I, D, R> {
+        self.alignments = alignments;
+        self
+    }
+
+    /// Highlight the selected row and column of the given state (the cell at their intersection
+    /// when both are selected) and scroll the table to keep them visible. The offsets of the
+    /// state are updated when the table is drawn.
+    pub fn state(&mut self, state: &'a mut TableState) -> &mut Table<'a, 'i, T, H, I, D, R> {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn highlight_style(&mut self, style: Style) -> &mut Table<'a, 'i, T, H, I, D, R> {
+        self.highlight_style = style;
+        self
+    }
 }
 
 impl<'a, 'i, T, H, I, D, R> Widget for Table<'a, 'i, T, H, I, D, R>
@@ -169,23 +316,35 @@ where
         // Set the background
         self.background(&table_area, buf, self.style.bg);
 
-        // Save widths of the columns that will fit in the given area
-        let mut x = 0;
-        let mut widths = Vec::with_capacity(self.widths.len());
-        for width in self.widths.iter() {
-            if x + width < table_area.width {
-                widths.push(*width);
+        let visible_rows = table_area.height.saturating_sub(2) as usize;
+        let state = match self.state {
+            Some(ref mut state) => {
+                state.scroll_to_selection(visible_rows, self.widths, table_area.width);
+                **state
             }
-            x += *width;
-        }
+            None => TableState::default(),
+        };
+
+        // Save widths of the columns that will fit in the given area
+        let widths = &self.widths[state.col_offset.min(self.widths.len())..];
+        let widths = &widths[..fitting_columns(widths, table_area.width)];
 
         let mut y = table_area.top();
 
         // Draw header
         if y < table_area.bottom() {
-            x = table_area.left();
-            for (w, t) in widths.iter().zip(self.header.by_ref()) {
-                buf.set_string(x, y, &format!("{}", t), &self.header_style);
+            let mut x = table_area.left();
+            let header = self.header.by_ref().skip(state.col_offset);
+            for (j, (w, t)) in widths.iter().zip(header).enumerate() {
+                // Left-aligned titles may overflow their column
+                match self.alignments.get(state.col_offset + j) {
+                    Some(&alignment) if alignment != Alignment::Left => {
+                        let title = format!("{}", t);
+                        let style = &self.header_style;
+                        buf.set_stringn_aligned(x, y, &title, *w as usize, alignment, style);
+                    }
+                    _ => buf.set_string(x, y, &format!("{}", t), &self.header_style),
+                }
                 x += *w + self.column_spacing;
             }
         }
@@ -195,17 +354,156 @@ where
         let default_style = Style::default();
         if y < table_area.bottom() {
             let remaining = (table_area.bottom() - y) as usize;
-            for (i, row) in self.rows.by_ref().take(remaining).enumerate() {
+            let rows = self.rows.by_ref().skip(state.row_offset).take(remaining);
+            for (i, row) in rows.enumerate() {
                 let (data, style) = match row {
                     Row::Data(d) => (d, &default_style),
                     Row::StyledData(d, s) => (d, s),
                 };
-                x = table_area.left();
-                for (w, elt) in widths.iter().zip(data) {
-                    buf.set_stringn(x, y + i as u16, &format!("{}", elt), *w as usize, style);
+                let mut x = table_area.left();
+                for (j, (w, elt)) in widths.iter().zip(data.skip(state.col_offset)).enumerate() {
+                    let (row, col) = (state.row_offset + i, state.col_offset + j);
+                    let highlighted = (state.selected_row.is_some()
+                        || state.selected_col.is_some())
+                        && state.selected_row.is_none_or(|r| r == row)
+                        && state.selected_col.is_none_or(|c| c == col);
+                    let style = if highlighted {
+                        &self.highlight_style
+                    } else {
+                        style
+                    };
+                    let alignment = self.alignments.get(col).cloned().unwrap_or(Alignment::Left);
+                    let text = format!("{}", elt);
+                    buf.set_stringn_aligned(x, y + i as u16, &text, *w as usize, alignment, style);
                     x += *w + self.column_spacing;
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell as StdCell;
+    use std::fmt;
+    use style::Color;
+
+    #[test]
+    fn selecting_a_hidden_column_scrolls_the_columns() {
+        let rows = vec![["a1", "b1", "c1"], ["a2", "b2", "c2"], ["a3", "b3", "c3"]];
+        let mut state = TableState::default();
+        state.selected_row = Some(2);
+        state.select_next_col(3);
+        state.select_next_col(3);
+        state.select_next_col(3);
+        assert_eq!(state.selected_col, Some(2));
+
+        let highlight = Style::default().fg(Color::Yellow);
+        let area = Rect::new(0, 0, 12, 4);
+        let mut buf = Buffer::empty(area);
+        Table::new(
+            ["A", "B", "C"].iter(),
+            rows.iter().map(|row| Row::Data(row.iter())),
+        ).widths(&[5, 5, 5])
+            .state(&mut state)
+            .highlight_style(highlight)
+            .draw(&area, &mut buf);
+
+        // Only two columns and two rows fit
+        assert_eq!(state.col_offset, 1);
+        assert_eq!(state.row_offset, 1);
+        assert_eq!(buf.to_string(), "B     C     \n            \nb2    c2    \nb3    c3    ");
+        assert_eq!(buf.get(6, 3).style, highlight);
+        assert_eq!(buf.get(0, 3).style, Style::default());
+    }
+
+    /// Counts how many times it is formatted
+    struct Counted<'c>(&'c StdCell<usize>);
+
+    impl<'c> Display for Counted<'c> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            write!(f, "x")
+        }
+    }
+
+    #[test]
+    fn only_the_displayed_rows_are_rendered() {
+        let pulled = StdCell::new(0);
+        let formatted = StdCell::new(0);
+        let mut state = TableState::default();
+        state.selected_row = Some(5000);
+        let area = Rect::new(0, 0, 10, 7);
+        let mut buf = Buffer::empty(area);
+        Table::new(
+            ["a", "b"].iter(),
+            (0..100_000)
+                .inspect(|_| pulled.set(pulled.get() + 1))
+                .map(|_| Row::Data(vec![Counted(&formatted), Counted(&formatted)].into_iter())),
+        ).widths(&[3, 3])
+            .state(&mut state)
+            .draw(&area, &mut buf);
+
+        assert_eq!(state.row_offset, 4996);
+        // The rows below the window are never pulled and only the displayed ones are formatted,
+        // those above it are pulled and built to be skipped
+        assert_eq!(pulled.get(), 5001);
+        assert_eq!(formatted.get(), 5 * 2);
+    }
+
+    /// Rows of squares which are only built once they are pulled, skipping them being free
+    struct Squares<'c> {
+        n: u64,
+        built: &'c StdCell<usize>,
+    }
+
+    impl<'c> Iterator for Squares<'c> {
+        type Item = Row<'static, ::std::vec::IntoIter<u64>, u64>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.built.set(self.built.get() + 1);
+            self.n += 1;
+            Some(Row::Data(vec![self.n - 1, (self.n - 1) * (self.n - 1)].into_iter()))
+        }
+
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            self.n += n as u64;
+            self.next()
+        }
+    }
+
+    #[test]
+    fn row_sources_which_can_seek_only_build_the_displayed_rows() {
+        let built = StdCell::new(0);
+        let mut state = TableState::default();
+        state.selected_row = Some(5000);
+        let area = Rect::new(0, 0, 10, 7);
+        let mut buf = Buffer::empty(area);
+        Table::new(["n", "n²"].iter(), Squares { n: 0, built: &built })
+            .widths(&[4, 4])
+            .state(&mut state)
+            .draw(&area, &mut buf);
+
+        assert_eq!(state.row_offset, 4996);
+        assert_eq!(built.get(), 5);
+        assert!(buf.to_string().lines().last().unwrap().starts_with("5000"));
+    }
+
+    #[test]
+    fn right_aligned_columns_are_flush_to_their_right_edge() {
+        let rows = vec![["apple", "3"], ["kiwi", "1250"], ["ｆｉｇ", "42"]];
+        let area = Rect::new(0, 0, 14, 5);
+        let mut buf = Buffer::empty(area);
+        Table::new(
+            ["Fruit", "Qty"].iter(),
+            rows.iter().map(|row| Row::Data(row.iter())),
+        ).widths(&[6, 6])
+            .alignments(&[Alignment::Left, Alignment::Right])
+            .draw(&area, &mut buf);
+        assert_eq!(
+            buf.to_string(),
+            "Fruit     Qty \n              \napple       3 \nkiwi     1250 \nｆｉｇ     42 "
+        );
+    }
+}
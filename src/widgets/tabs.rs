@@ -2,7 +2,7 @@ use unicode_width::UnicodeWidthStr;
 
 use widgets::{Block, Widget};
 use buffer::Buffer;
-use layout::Rect;
+use layout::{Alignment, Rect};
 use style::Style;
 use symbols::line;
 
@@ -36,6 +36,8 @@ where
     style: Style,
     /// The style used to display the selected item
     highlight_style: Style,
+    /// Alignment of the tabs within the available width
+    alignment: Alignment,
 }
 
 impl<'a, T> Default for Tabs<'a, T>
@@ -49,6 +51,7 @@ where
             selected: 0,
             style: Default::default(),
             highlight_style: Default::default(),
+            alignment: Alignment::Left,
         }
     }
 }
@@ -81,6 +84,58 @@ where
         self.highlight_style = style;
         self
     }
+
+    pub fn alignment(&mut self, alignment: Alignment) -> &mut Tabs<'a, T> {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the area covered by each visible tab, title and surrounding spaces, when the
+    /// widget is drawn in the given area, e.g. to register them in a `HitMap`
+    pub fn regions(&self, area: &Rect) -> Vec<Rect> {
+        let tabs_area = match self.block {
+            Some(ref b) => b.inner(area),
+            None => *area,
+        };
+        if tabs_area.height < 1 {
+            return Vec::new();
+        }
+        self.title_offsets(&tabs_area)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let width = self.titles[i].as_ref().width() as u16 + 2;
+                Rect::new(x - 1, tabs_area.top(), width, 1).intersection(&tabs_area)
+            })
+            .collect()
+    }
+
+    /// Returns the column where each visible title starts
+    fn title_offsets(&self, tabs_area: &Rect) -> Vec<u16> {
+        // Each tab is surrounded by a space on both sides and followed by a divider, except for
+        // the last one
+        let width = self
+            .titles
+            .iter()
+            .map(|t| t.as_ref().width() as u16 + 3)
+            .fold(0u16, |acc, w| acc.saturating_add(w))
+            .saturating_sub(1);
+        let mut x = tabs_area.left() + self.alignment.offset(width, tabs_area.width);
+        let mut offsets = Vec::new();
+        for title in self.titles {
+            x += 1;
+            if x > tabs_area.right() {
+                break;
+            }
+            offsets.push(x);
+            x += title.as_ref().width() as u16 + 1;
+            if x >= tabs_area.right() {
+                break;
+            }
+            x += 1;
+        }
+        offsets
+    }
 }
 
 impl<'a, T> Widget for Tabs<'a, T>
@@ -102,30 +157,43 @@ where
 
         self.background(&tabs_area, buf, self.style.bg);
 
-        let mut x = tabs_area.left();
-        for (title, style) in self.titles.iter().enumerate().map(|(i, t)| {
-            if i == self.selected {
-                (t, &self.highlight_style)
+        for (i, x) in self.title_offsets(&tabs_area).into_iter().enumerate() {
+            let title = self.titles[i].as_ref();
+            let style = if i == self.selected {
+                &self.highlight_style
             } else {
-                (t, &self.style)
-            }
-        }) {
-            x += 1;
-            if x > tabs_area.right() {
-                break;
-            } else {
-                buf.set_string(x, tabs_area.top(), title.as_ref(), style);
-                x += title.as_ref().width() as u16 + 1;
-                if x >= tabs_area.right() {
-                    break;
-                } else {
-                    buf.get_mut(x, tabs_area.top())
-                        .set_symbol(line::VERTICAL)
-                        .set_fg(self.style.fg)
-                        .set_bg(self.style.bg);
-                    x += 1;
-                }
+                &self.style
+            };
+            buf.set_string(x, tabs_area.top(), title, style);
+            let divider = x + title.width() as u16 + 1;
+            if i + 1 < self.titles.len() && divider < tabs_area.right() {
+                buf.get_mut(divider, tabs_area.top())
+                    .set_symbol(line::VERTICAL)
+                    .set_fg(self.style.fg)
+                    .set_bg(self.style.bg);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_divider_follows_the_last_tab() {
+        let area = Rect::new(0, 0, 11, 1);
+        for &(alignment, expected) in &[
+            (Alignment::Left, " ab │ cd   "),
+            (Alignment::Center, "  ab │ cd  "),
+            (Alignment::Right, "   ab │ cd "),
+        ] {
+            let mut buf = Buffer::empty(area);
+            Tabs::default()
+                .titles(&["ab", "cd"])
+                .alignment(alignment)
+                .draw(&area, &mut buf);
+            assert_eq!(buf.to_string(), expected);
+        }
+    }
+}